@@ -0,0 +1,39 @@
+//! Checks a modpack manifest before it's published.
+//!
+//! Usage: manifest-lint <manifest path or url> [<modpack source> <branch>]
+//! e.g. manifest-lint manifest.json Commander07/modpack-test/ main
+
+use std::{env, process::ExitCode};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some(manifest) = args.first() else {
+        eprintln!("Usage: manifest-lint <manifest path or url> [<modpack source> <branch>]");
+        return ExitCode::FAILURE;
+    };
+    if args.len() < 3 {
+        println!("No modpack source and branch given, includes won't be checked");
+    }
+    let res = futures::executor::block_on(installer::lint_manifest(
+        manifest,
+        args.get(1).map(|x| x.as_str()),
+        args.get(2).map(|x| x.as_str()),
+    ));
+    match res {
+        Ok(problems) if problems.is_empty() => {
+            println!("No problems found in '{manifest}'");
+            ExitCode::SUCCESS
+        }
+        Ok(problems) => {
+            println!("Found {} problem(s) in '{manifest}':", problems.len());
+            for problem in problems {
+                println!("- {problem}");
+            }
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
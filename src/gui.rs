@@ -1,13 +1,33 @@
-use std::{collections::BTreeMap, path::PathBuf};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::PathBuf,
+    sync::atomic::Ordering,
+    time::Duration,
+};
 
 use base64::{engine, Engine};
+use dioxus::desktop::{
+    tao::event::{Event, WindowEvent},
+    use_wry_event_handler,
+};
 use dioxus::prelude::*;
+use i18n::t;
+use log::{info, warn};
 use modal::{Modal, ModalContext};
 
-use crate::{get_app_data, get_installed_packs, get_launcher, uninstall, Launcher, PackName};
+use crate::{
+    get_app_data, get_diagnostics, get_installed_packs, get_launcher, uninstall, Launcher,
+    PackName,
+};
 
+mod i18n;
 mod modal;
 
+const UPDATE_POLL_INTERVAL: Duration = Duration::from_secs(5 * 60);
+const MAX_UPDATE_POLL_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// Progress events buffered before the install waits for the progress bar to catch up
+const INSTALL_EVENT_BUFFER: usize = 64;
+
 #[derive(Clone)]
 struct TabInfo {
     color: String,
@@ -19,7 +39,13 @@ struct TabInfo {
 }
 
 #[component]
-fn ProgressView(value: i64, max: i64, status: String, title: String) -> Element {
+fn ProgressView(
+    value: i64,
+    max: i64,
+    status: String,
+    title: String,
+    on_cancel: EventHandler<MouseEvent>,
+) -> Element {
     rsx!(
         div { class: "version-container",
             div { class: "subtitle-container",
@@ -28,11 +54,182 @@ fn ProgressView(value: i64, max: i64, status: String, title: String) -> Element
             div { class: "container", style: "justify-items: center;",
                 progress { max, value: "{value}" }
                 p { class: "progress-status", "{status}" }
+                button {
+                    class: "uninstall-button",
+                    onclick: move |evt| on_cancel.call(evt),
+                    {t("cancel")}
+                }
+            }
+        }
+    )
+}
+
+/// Shown before installing so the user can check what and where is about to be installed
+#[component]
+fn InstallSummary(
+    title: String,
+    features: Vec<String>,
+    loader: String,
+    downloads: usize,
+    directory: String,
+    /// What an update changes, 'None' unless updating
+    changes: Option<super::UpdateChanges>,
+    /// Problems found by 'precheck', 'None' while it's still running
+    problems: Option<Vec<String>>,
+    on_confirm: EventHandler<MouseEvent>,
+    on_back: EventHandler<MouseEvent>,
+) -> Element {
+    let features = if features.is_empty() {
+        t("summary.none")
+    } else {
+        features.join(", ")
+    };
+    rsx!(
+        div { class: "version-container",
+            div { class: "subtitle-container",
+                h1 { "{title}" }
+            }
+            div { class: "container",
+                ul { class: "summary",
+                    li { {format!("{} {loader}", t("summary.loader"))} }
+                    li { {format!("{} {features}", t("summary.features"))} }
+                    li { {format!("{} {downloads}", t("summary.downloads"))} }
+                    li { {format!("{} {directory}", t("summary.directory"))} }
+                }
+                if let Some(changes) = changes {
+                    if changes.is_empty() {
+                        p { {t("summary.no_changes")} }
+                    } else {
+                        p { {t("summary.changes")} }
+                        ul { class: "summary changes",
+                            for name in changes.added {
+                                li { {format!("{} {name}", t("summary.added"))} }
+                            }
+                            for name in changes.updated {
+                                li { {format!("{} {name}", t("summary.updated"))} }
+                            }
+                            for name in changes.removed {
+                                li { {format!("{} {name}", t("summary.removed"))} }
+                            }
+                        }
+                    }
+                }
+                match problems {
+                    None => rsx!(p { {t("summary.checking")} }),
+                    Some(problems) if problems.is_empty() => rsx!(p { {t("summary.reachable")} }),
+                    Some(problems) => rsx!(
+                        p { {t("summary.unreachable")} }
+                        ul { class: "summary",
+                            for problem in problems {
+                                li { "{problem}" }
+                            }
+                        }
+                    ),
+                }
+                div {
+                    button {
+                        class: "install-button",
+                        onclick: move |evt| on_confirm.call(evt),
+                        {t("summary.confirm")}
+                    }
+                    button {
+                        class: "uninstall-button",
+                        onclick: move |evt| on_back.call(evt),
+                        {t("back")}
+                    }
+                }
+            }
+        }
+    )
+}
+
+/// Shown after a successful install, update or modification until the user returns to the feature screen
+#[component]
+fn InstallComplete(
+    title: String,
+    status: String,
+    launch: String,
+    changelog: Option<String>,
+    /// Offered to the user together with the exact command, it's never run without them pressing the button
+    post_install_command: Option<String>,
+    /// Locale key of the post install command's progress, 'None' until it's run
+    post_install_status: Option<&'static str>,
+    on_run_post_install: EventHandler<MouseEvent>,
+    on_done: EventHandler<MouseEvent>,
+) -> Element {
+    rsx!(
+        div { class: "version-container",
+            div { class: "subtitle-container",
+                h1 { "{title}" }
+            }
+            div { class: "container",
+                h2 { "{status}" }
+                p { "{launch}" }
+                if let Some(changelog) = changelog {
+                    p {
+                        a { href: "{changelog}", {t("complete.changelog")} }
+                    }
+                }
+                if let Some(command) = post_install_command {
+                    p { {t("complete.post_install_text")} }
+                    textarea { class: "error-area", readonly: true, "{command}" }
+                    match post_install_status {
+                        Some(status) => rsx!(p { {t(status)} }),
+                        None => rsx!(
+                            button {
+                                class: "install-button",
+                                onclick: move |evt| on_run_post_install.call(evt),
+                                {t("complete.post_install_run")}
+                            }
+                        ),
+                    }
+                }
+                button {
+                    class: "install-button",
+                    onclick: move |evt| on_done.call(evt),
+                    {t("complete.done")}
+                }
             }
         }
     )
 }
 
+/// Lists the contents of a modpack before a launcher is chosen, closing it sets 'browse' to false
+#[component]
+fn Preview(
+    modpack_source: String,
+    modpack_branch: String,
+    mut browse: Signal<bool>,
+    mut error: Signal<Option<String>>,
+) -> Element {
+    let profile = use_resource(move || {
+        let source = modpack_source.clone();
+        let branch = modpack_branch.clone();
+        async move { super::init_preview(source, branch).await }
+    });
+    // Bound first as the read guard must be dropped before 'profile'
+    let res = match &*profile.read() {
+        None => rsx!(div { class: "container", {t("loading")} }),
+        Some(Err(e)) => {
+            error.set(Some(e.to_string() + " (Failed to retrieve modpack preview!)"));
+            browse.set(false);
+            None
+        }
+        Some(Ok(manifest)) => {
+            // Everything the modpack can install is listed, not just the default features
+            let manifest = manifest.clone();
+            let mut enabled = vec![super::default_id()];
+            enabled.extend(manifest.features.iter().map(|x| x.id.clone()));
+            rsx!(Credits {
+                manifest,
+                enabled,
+                credits: browse
+            })
+        }
+    };
+    res
+}
+
 #[derive(PartialEq, Props, Clone)]
 struct CreditsProps {
     manifest: super::Manifest,
@@ -42,6 +239,7 @@ struct CreditsProps {
 
 #[component]
 fn Credits(mut props: CreditsProps) -> Element {
+    let by = t("credits.by");
     rsx! {
         div { class: "version-container",
             div { class: "subtitle-container",
@@ -65,7 +263,7 @@ fn Credits(mut props: CreditsProps) -> Element {
                                 for r#mod in props.manifest.mods {
                                     if props.enabled.contains(&r#mod.id) {
                                         li {
-                                            "{r#mod.name} by "
+                                            "{r#mod.name} {by} "
                                             for author in &r#mod.authors {
                                                 a { href: "{author.link}",
                                                     if r#mod.authors.last().unwrap() == author {
@@ -81,7 +279,7 @@ fn Credits(mut props: CreditsProps) -> Element {
                                 for shaderpack in props.manifest.shaderpacks {
                                     if props.enabled.contains(&shaderpack.id) {
                                         li {
-                                            "{shaderpack.name} by "
+                                            "{shaderpack.name} {by} "
                                             for author in &shaderpack.authors {
                                                 a { href: "{author.link}",
                                                     if shaderpack.authors.last().unwrap() == author {
@@ -97,7 +295,7 @@ fn Credits(mut props: CreditsProps) -> Element {
                                 for resourcepack in props.manifest.resourcepacks {
                                     if props.enabled.contains(&resourcepack.id) {
                                         li {
-                                            "{resourcepack.name} by "
+                                            "{resourcepack.name} {by} "
                                             for author in &resourcepack.authors {
                                                 a { href: "{author.link}",
                                                     if resourcepack.authors.last().unwrap() == author {
@@ -115,7 +313,7 @@ fn Credits(mut props: CreditsProps) -> Element {
                                         && include.name.is_some()
                                     {
                                         li {
-                                            "{include.name.as_ref().unwrap()} by "
+                                            "{include.name.as_ref().unwrap()} {by} "
                                             for author in &include.authors.as_ref().unwrap() {
                                                 a { href: "{author.link}",
                                                     if include.authors.as_ref().unwrap().last().unwrap() == author {
@@ -155,6 +353,102 @@ fn PackUninstallButton(launcher: Launcher, pack: PackName) -> Element {
     )
 }
 
+fn detected_launchers(config: &super::Config) -> Vec<Launcher> {
+    let mut launchers = vec![];
+    if super::get_minecraft_folder().is_ok_and(|x| x.is_dir()) {
+        if let Ok(app_data) = get_app_data() {
+            launchers.push(Launcher::Vanilla(app_data));
+        }
+    }
+    for multimc in ["multimc-MultiMC", "multimc-PrismLauncher", "modrinth"] {
+        if let Ok(launcher) = get_launcher(multimc) {
+            launchers.push(launcher);
+        }
+    }
+    if config.launcher.starts_with("custom") {
+        if let Ok(launcher) = get_launcher(&config.launcher) {
+            if !launchers.contains(&launcher) {
+                launchers.push(launcher);
+            }
+        }
+    }
+    launchers
+}
+
+fn launcher_title(launcher: &Launcher) -> String {
+    match launcher {
+        Launcher::Vanilla(root) | Launcher::MultiMC(root) | Launcher::Modrinth(root) => {
+            format!("{launcher} ({})", root.display())
+        }
+    }
+}
+
+#[component]
+fn InstalledPack(
+    launcher: Launcher,
+    pack: PackName,
+    latest_version: Option<String>,
+    error: Signal<Option<String>>,
+) -> Element {
+    let mut hidden = use_signal(|| false);
+    let status = match latest_version {
+        Some(ref v) if *v != pack.modpack_version => t("pack.update_available"),
+        Some(_) => t("pack.up_to_date"),
+        None => t("pack.unknown"),
+    };
+    rsx!(
+        li { hidden,
+            "{pack.name} ({pack.modpack_version}) - {status}"
+            button {
+                class: "pack-uninstall",
+                onclick: move |_| {
+                    match uninstall(&launcher, &pack.uuid) {
+                        Ok(_) => *hidden.write() = true,
+                        Err(e) => error.set(Some(format!("{:#?}", e) + " (Failed to uninstall modpack!)")),
+                    }
+                },
+                {t("pack.uninstall")}
+            }
+        }
+    )
+}
+
+#[component]
+fn PackManager(
+    config: Signal<super::Config>,
+    latest_versions: Signal<HashMap<String, String>>,
+    error: Signal<Option<String>>,
+) -> Element {
+    let launchers = detected_launchers(&config.read());
+    rsx! {
+        div { class: "container", style: "width: 48vw;",
+            h1 { {t("manager.title")} }
+            if launchers.is_empty() {
+                p { {t("launcher.none_found")} }
+            }
+            for launcher in launchers {
+                div {
+                    h2 { {launcher_title(&launcher)} }
+                    match get_installed_packs(&launcher) {
+                        Ok(packs) if packs.is_empty() => rsx!(p { {t("manager.none_installed")} }),
+                        Ok(packs) => rsx!(ul {
+                            for pack in packs {
+                                InstalledPack {
+                                    launcher: launcher.clone(),
+                                    latest_version: latest_versions.read().get(&pack.uuid).cloned(),
+                                    pack,
+                                    error
+                                }
+                            }
+                        }),
+                        Err(_) => rsx!(p { {t("manager.none_installed")} }),
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[derive(PartialEq, Props, Clone)]
 struct SettingsProps {
     config: Signal<super::Config>,
@@ -166,22 +460,27 @@ struct SettingsProps {
 
 #[component]
 fn Settings(mut props: SettingsProps) -> Element {
+    let mut http_client = use_context::<Signal<super::CachedHttpClient>>();
     let mut vanilla = None;
     let mut multimc = None;
     let mut prism = None;
+    let mut modrinth = None;
     let mut custom = None;
-    let launcher = get_launcher(&props.config.read().launcher).unwrap();
-    let packs = match get_installed_packs(&launcher) {
-        Ok(v) => v,
-        Err(err) => {
+    // An unknown launcher (e.g. "other" from an old config) must not crash the settings, it can be changed here
+    let launcher = get_launcher(&props.config.read().launcher).ok();
+    let packs = match launcher.as_ref().map(get_installed_packs) {
+        Some(Ok(v)) => v,
+        Some(Err(err)) => {
             *props.error.write() = Some(err.to_string());
             return None;
         }
+        None => vec![],
     };
     match &props.config.read().launcher[..] {
         "vanilla" => vanilla = Some("true"),
         "multimc-MultiMC" => multimc = Some("true"),
         "multimc-PrismLauncher" => prism = Some("true"),
+        "modrinth" => modrinth = Some("true"),
         _ => {}
     }
     if props.config.read().launcher.starts_with("custom") {
@@ -197,43 +496,217 @@ fn Settings(mut props: SettingsProps) -> Element {
                         .config
                         .write()
                         .launcher = event.data.values()["launcher-select"].as_value();
-                    if let Err(e) = std::fs::write(
-                        &props.config_path,
-                        serde_json::to_vec(&*props.config.read()).unwrap(),
-                    ) {
+                    let proxy = event.data.values()["proxy"].as_value();
+                    props.config.write().proxy = if proxy.trim().is_empty() {
+                        None
+                    } else {
+                        Some(proxy.trim().to_string())
+                    };
+                    let max_mem = event.data.values()["max-mem"].as_value();
+                    props.config.write().max_mem = max_mem.trim().parse().ok();
+                    let locale = event.data.values()["locale-select"].as_value();
+                    props.config.write().locale = if locale.is_empty() { None } else { Some(locale) };
+                    props.config.write().show_beta = Some(
+                        event
+                            .data
+                            .values()
+                            .get("show-beta")
+                            .is_some_and(|v| v.as_value() == "true"),
+                    );
+                    props.config.write().shared_store = Some(
+                        event
+                            .data
+                            .values()
+                            .get("shared-store")
+                            .is_some_and(|v| v.as_value() == "true"),
+                    );
+                    props.config.write().insecure_ddl = Some(
+                        event
+                            .data
+                            .values()
+                            .get("insecure-ddl")
+                            .is_some_and(|v| v.as_value() == "true"),
+                    );
+                    props.config.write().force_manifest_jvm = Some(
+                        event
+                            .data
+                            .values()
+                            .get("force-manifest-jvm")
+                            .is_some_and(|v| v.as_value() == "true"),
+                    );
+                    props.config.write().install_counting = Some(
+                        event
+                            .data
+                            .values()
+                            .get("install-counting")
+                            .is_some_and(|v| v.as_value() == "true"),
+                    );
+                    props.config.write().compact_view = Some(
+                        event
+                            .data
+                            .values()
+                            .get("compact-view")
+                            .is_some_and(|v| v.as_value() == "true"),
+                    );
+                    props.config.write().separate_user_data = Some(
+                        event
+                            .data
+                            .values()
+                            .get("separate-user-data")
+                            .is_some_and(|v| v.as_value() == "true"),
+                    );
+                    if let Err(e) = super::save_config(&props.config_path, &props.config.read()) {
                         props.error.set(Some(format!("{:#?}", e) + " (Failed to write config!)"));
                     }
+                    // The proxy, redirect limits and endpoints are only read when a client is built
+                    http_client.set(super::CachedHttpClient::new());
                     props.settings.set(false);
                 },
                 div { class: "label",
-                    span { "Launcher:" }
+                    span { {t("launcher.label")} }
                     select {
                         name: "launcher-select",
                         id: "launcher-select",
                         form: "settings",
                         class: "credits-button",
-                        if super::get_minecraft_folder().is_dir() {
-                            option { value: "vanilla", selected: vanilla, "Vanilla" }
+                        if super::get_minecraft_folder().is_ok_and(|x| x.is_dir()) {
+                            option { value: "vanilla", selected: vanilla, {t("launcher.vanilla")} }
                         }
                         if super::get_multimc_folder("MultiMC").is_ok() {
-                            option { value: "multimc-MultiMC", selected: multimc, "MultiMC" }
+                            option { value: "multimc-MultiMC", selected: multimc, {t("launcher.multimc")} }
                         }
                         if super::get_multimc_folder("PrismLauncher").is_ok() {
                             option {
                                 value: "multimc-PrismLauncher",
                                 selected: prism,
-                                "Prism Launcher"
+                                {t("launcher.prism")}
                             }
                         }
+                        if super::get_modrinth_folder().is_ok() {
+                            option { value: "modrinth", selected: modrinth, {t("launcher.modrinth")} }
+                        }
                         if custom.is_some() {
                             option {
                                 value: "{props.config.read().launcher}",
                                 selected: custom,
-                                "Custom MultiMC"
+                                {t("launcher.custom")}
+                            }
+                        }
+                    }
+                }
+                div { class: "label",
+                    span { {t("settings.language")} }
+                    select {
+                        name: "locale-select",
+                        id: "locale-select",
+                        form: "settings",
+                        class: "credits-button",
+                        option {
+                            value: "",
+                            selected: props.config.read().locale.is_none(),
+                            {t("settings.language_system")}
+                        }
+                        for (code , name) in i18n::available() {
+                            option {
+                                value: "{code}",
+                                selected: props.config.read().locale.as_deref() == Some(code),
+                                "{name}"
                             }
                         }
                     }
                 }
+                div { class: "label",
+                    span { {t("settings.proxy")} }
+                    input {
+                        name: "proxy",
+                        r#type: "text",
+                        class: "proxy-input",
+                        placeholder: "http://host:port",
+                        value: props.config.read().proxy.clone().unwrap_or_default()
+                    }
+                }
+                label { class: "label tooltip",
+                    span { {t("settings.max_mem")} }
+                    input {
+                        name: "max-mem",
+                        r#type: "number",
+                        min: "512",
+                        class: "proxy-input",
+                        placeholder: super::get_system_max_mem().map(|v| v.to_string()).unwrap_or_default(),
+                        value: props.config.read().max_mem.map(|v| v.to_string()).unwrap_or_default()
+                    }
+                    span { class: "tooltiptext", {t("settings.max_mem_tooltip")} }
+                }
+                label { class: "tooltip",
+                    input {
+                        name: "shared-store",
+                        r#type: "checkbox",
+                        value: "true",
+                        checked: if props.config.read().shared_store.unwrap_or(false) { Some("true") } else { None }
+                    }
+                    {t("settings.shared_store")}
+                    span { class: "tooltiptext", {t("settings.shared_store_tooltip")} }
+                }
+                label { class: "tooltip",
+                    input {
+                        name: "force-manifest-jvm",
+                        r#type: "checkbox",
+                        value: "true",
+                        checked: if props.config.read().force_manifest_jvm.unwrap_or(false) { Some("true") } else { None }
+                    }
+                    {t("settings.force_manifest_jvm")}
+                    span { class: "tooltiptext", {t("settings.force_manifest_jvm_tooltip")} }
+                }
+                label { class: "tooltip",
+                    input {
+                        name: "insecure-ddl",
+                        r#type: "checkbox",
+                        value: "true",
+                        checked: if props.config.read().insecure_ddl.unwrap_or(false) { Some("true") } else { None }
+                    }
+                    {t("settings.insecure_ddl")}
+                    span { class: "tooltiptext", {t("settings.insecure_ddl_tooltip")} }
+                }
+                label { class: "tooltip",
+                    input {
+                        name: "show-beta",
+                        r#type: "checkbox",
+                        value: "true",
+                        checked: if props.config.read().show_beta.unwrap_or(false) { Some("true") } else { None }
+                    }
+                    {t("settings.show_beta")}
+                    span { class: "tooltiptext", {t("settings.show_beta_tooltip")} }
+                }
+                label { class: "tooltip",
+                    input {
+                        name: "install-counting",
+                        r#type: "checkbox",
+                        value: "true",
+                        checked: if props.config.read().install_counting.unwrap_or(false) { Some("true") } else { None }
+                    }
+                    {t("settings.install_counting")}
+                    span { class: "tooltiptext", {t("settings.install_counting_tooltip")} }
+                }
+                label { class: "tooltip",
+                    input {
+                        name: "compact-view",
+                        r#type: "checkbox",
+                        value: "true",
+                        checked: if props.config.read().compact_view.unwrap_or(false) { Some("true") } else { None }
+                    }
+                    {t("settings.compact_view")}
+                    span { class: "tooltiptext", {t("settings.compact_view_tooltip")} }
+                }
+                label { class: "tooltip",
+                    input {
+                        name: "separate-user-data",
+                        r#type: "checkbox",
+                        value: "true",
+                        checked: if props.config.read().separate_user_data.unwrap_or(false) { Some("true") } else { None }
+                    }
+                    {t("settings.separate_user_data")}
+                    span { class: "tooltiptext", {t("settings.separate_user_data_tooltip")} }
+                }
                 CustomMultiMCButton {
                     config: props.config,
                     config_path: props.config_path.clone(),
@@ -242,7 +715,7 @@ fn Settings(mut props: SettingsProps) -> Element {
                 }
                 input {
                     r#type: "submit",
-                    value: "Save",
+                    value: t("save"),
                     class: "install-button",
                     id: "save"
                 }
@@ -254,11 +727,12 @@ fn Settings(mut props: SettingsProps) -> Element {
                         let mut modal = use_context::<ModalContext>();
                         modal
                             .open(
-                                "Select modpack to uninstall.",
+                                t("settings.uninstall_title"),
                                 rsx! {
                                     ul {
                                         for pack in packs.clone() {
-                                            PackUninstallButton { launcher: launcher.clone(), pack }
+                                            // 'packs' is empty without a launcher
+                                            PackUninstallButton { launcher: launcher.clone().unwrap(), pack }
                                         }
                                     }
                                 },
@@ -267,7 +741,21 @@ fn Settings(mut props: SettingsProps) -> Element {
                             );
                         evt.stop_propagation();
                     },
-                    "Uninstall"
+                    {t("settings.uninstall")}
+                }
+                button {
+                    class: "install-button",
+                    r#type: "button",
+                    onclick: move |evt| {
+                        let report = get_diagnostics(&props.config.read());
+                        // Serializing to JSON gives us a correctly escaped JS string literal
+                        let _ = eval(&format!(
+                            "navigator.clipboard.writeText({});",
+                            serde_json::to_string(&report).unwrap()
+                        ));
+                        evt.stop_propagation();
+                    },
+                    {t("settings.diagnostics")}
                 }
             }
         }
@@ -287,15 +775,18 @@ fn Launcher(mut props: LauncherProps) -> Element {
     let mut vanilla = None;
     let mut multimc = None;
     let mut prism = None;
+    let mut modrinth = None;
     match &props.config.read().launcher[..] {
         "vanilla" => vanilla = Some("true"),
         "multimc-MultiMC" => multimc = Some("true"),
         "multimc-PrismLauncher" => prism = Some("true"),
+        "modrinth" => modrinth = Some("true"),
         _ => {}
     }
-    let has_supported_launcher = super::get_minecraft_folder().is_dir()
+    let has_supported_launcher = super::get_minecraft_folder().is_ok_and(|x| x.is_dir())
         || super::get_multimc_folder("MultiMC").is_ok()
-        || super::get_multimc_folder("PrismLauncher").is_ok();
+        || super::get_multimc_folder("PrismLauncher").is_ok()
+        || super::get_modrinth_folder().is_ok();
     if !has_supported_launcher {
         rsx!(NoLauncherFound {
             config: props.config,
@@ -314,35 +805,39 @@ fn Launcher(mut props: LauncherProps) -> Element {
                             .write()
                             .launcher = event.data.values()["launcher-select"].as_value();
                         props.config.write().first_launch = Some(false);
-                        if let Err(e) = std::fs::write(
-                            &props.config_path,
-                            serde_json::to_vec(&*props.config.read()).unwrap(),
-                        ) {
+                        if let Err(e) = super::save_config(&props.config_path, &props.config.read()) {
                             props.error.set(Some(format!("{:#?}", e) + " (Failed to write config!)"));
                         }
                     },
                     div { class: "label",
-                        span { "Launcher:" }
+                        span { {t("launcher.label")} }
                         select {
                             name: "launcher-select",
                             id: "launcher-select",
                             form: "settings",
                             class: "credits-button",
-                            if super::get_minecraft_folder().is_dir() {
-                                option { value: "vanilla", selected: vanilla, "Vanilla" }
+                            if super::get_minecraft_folder().is_ok_and(|x| x.is_dir()) {
+                                option { value: "vanilla", selected: vanilla, {t("launcher.vanilla")} }
                             }
                             if super::get_multimc_folder("MultiMC").is_ok() {
                                 option {
                                     value: "multimc-MultiMC",
                                     selected: multimc,
-                                    "MultiMC"
+                                    {t("launcher.multimc")}
                                 }
                             }
                             if super::get_multimc_folder("PrismLauncher").is_ok() {
                                 option {
                                     value: "multimc-PrismLauncher",
                                     selected: prism,
-                                    "Prism Launcher"
+                                    {t("launcher.prism")}
+                                }
+                            }
+                            if super::get_modrinth_folder().is_ok() {
+                                option {
+                                    value: "modrinth",
+                                    selected: modrinth,
+                                    {t("launcher.modrinth")}
                                 }
                             }
                         }
@@ -355,7 +850,7 @@ fn Launcher(mut props: LauncherProps) -> Element {
                     }
                     input {
                         r#type: "submit",
-                        value: "Continue",
+                        value: t("continue"),
                         class: "install-button",
                         id: "save"
                     }
@@ -368,9 +863,10 @@ fn Launcher(mut props: LauncherProps) -> Element {
 #[component]
 fn CustomMultiMCButton(mut props: LauncherProps) -> Element {
     let custom_multimc = move |_evt| {
-        let directory_dialog = rfd::FileDialog::new()
-            .set_title("Pick root directory of desired MultiMC based launcher.")
-            .set_directory(get_app_data());
+        let mut directory_dialog = rfd::FileDialog::new().set_title(t("launcher.custom_dialog"));
+        if let Ok(app_data) = get_app_data() {
+            directory_dialog = directory_dialog.set_directory(app_data);
+        }
         let directory = directory_dialog.pick_folder();
         match directory {
             Some(path) => {
@@ -385,10 +881,7 @@ fn CustomMultiMCButton(mut props: LauncherProps) -> Element {
                 }
                 props.config.write().launcher = format!("custom-{}", path.unwrap());
                 props.config.write().first_launch = Some(false);
-                if let Err(e) = std::fs::write(
-                    &props.config_path,
-                    serde_json::to_vec(&*props.config.read()).unwrap(),
-                ) {
+                if let Err(e) = super::save_config(&props.config_path, &props.config.read()) {
                     props
                         .error
                         .set(Some(format!("{:#?}", e) + " (Failed to write config!)"));
@@ -402,7 +895,7 @@ fn CustomMultiMCButton(mut props: LauncherProps) -> Element {
             class: "install-button custom-multimc-button",
             onclick: custom_multimc,
             r#type: "button",
-            "Use custom MultiMC directory"
+            {t("launcher.custom_button")}
         }
     )
 }
@@ -411,12 +904,12 @@ fn CustomMultiMCButton(mut props: LauncherProps) -> Element {
 fn NoLauncherFound(props: LauncherProps) -> Element {
     rsx! {
         div { class: "container", style: "width: 48vw;",
-            h1 { "No supported launcher found!" }
+            h1 { {t("launcher.none_found")} }
             p {
-                "Only Prism Launcher, MultiMC and the vanilla launcher are supported by default, other MultiMC launchers can be added using the button below."
+                {t("launcher.none_found_supported")}
                 br {}
                 br {}
-                "If you have any of these installed then please make sure you are on the latest version of the installer, if you are, open a thread in #📂modpack-issues on the discord. Please make sure your thread contains the following information: Launcher your having issues with, directory of the launcher and your OS."
+                {t("launcher.none_found_help")}
             }
             CustomMultiMCButton {
                 config: props.config,
@@ -428,6 +921,31 @@ fn NoLauncherFound(props: LauncherProps) -> Element {
     }
 }
 
+/// Drives the progress bar with the 'InstallEvent's of installs done with 'installer_profile'
+fn track_progress(
+    installer_profile: &mut super::InstallerProfile,
+    mut install_progress: Signal<i64>,
+    mut install_item_amount: Signal<usize>,
+) {
+    let (events, mut receiver) = tokio::sync::mpsc::channel(INSTALL_EVENT_BUFFER);
+    installer_profile.subscribe(events);
+    install_progress.set(0);
+    // Ends once the install is done and the profile holding the sender is dropped
+    spawn(async move {
+        while let Some(event) = receiver.recv().await {
+            match event {
+                super::InstallEvent::Started { total } => install_item_amount.set(total),
+                super::InstallEvent::ItemDownloaded { .. }
+                | super::InstallEvent::ItemSkipped { .. }
+                | super::InstallEvent::IncludeExtracted { .. } => {
+                    install_progress.with_mut(|x| *x += 1)
+                }
+                super::InstallEvent::Finished | super::InstallEvent::Failed { .. } => (),
+            }
+        }
+    });
+}
+
 fn feature_change(
     local_features: Signal<Option<Vec<String>>>,
     mut modify: Signal<bool>,
@@ -467,6 +985,54 @@ fn feature_change(
     }
 }
 
+/// Enables exactly the visible features of 'preset', hidden features are left as is
+fn apply_preset(
+    local_features: Signal<Option<Vec<String>>>,
+    mut modify: Signal<bool>,
+    preset: &super::Preset,
+    features: &[super::Feature],
+    mut modify_count: Signal<i32>,
+    mut enabled_features: Signal<Vec<String>>,
+) {
+    enabled_features.with_mut(|x| {
+        x.retain(|id| {
+            features
+                .iter()
+                .find(|feat| &feat.id == id)
+                .is_none_or(|feat| feat.hidden)
+        });
+        for id in &preset.features {
+            if !x.contains(id) {
+                x.push(id.clone());
+            }
+        }
+    });
+    if let Some(local) = &*local_features.read() {
+        let changed = features
+            .iter()
+            .filter(|feat| {
+                !feat.hidden
+                    && local.contains(&feat.id) != enabled_features.read().contains(&feat.id)
+            })
+            .count() as i32;
+        modify_count.set(changed);
+        modify.set(changed > 0);
+    }
+}
+
+/// Groups the visible features by category in manifest order, categories are sorted by name and followed by the uncategorized features
+fn group_features(features: &[super::Feature]) -> Vec<(Option<String>, Vec<super::Feature>)> {
+    let mut groups: Vec<(Option<String>, Vec<super::Feature>)> = vec![];
+    for feat in features.iter().filter(|x| !x.hidden) {
+        match groups.iter_mut().find(|(category, _)| category == &feat.category) {
+            Some((_, group)) => group.push(feat.clone()),
+            None => groups.push((feat.category.clone(), vec![feat.clone()])),
+        }
+    }
+    groups.sort_by_key(|(category, _)| (category.is_none(), category.clone()));
+    groups
+}
+
 #[derive(PartialEq, Props, Clone)]
 struct VersionProps {
     modpack_source: String,
@@ -476,34 +1042,84 @@ struct VersionProps {
     name: Signal<String>,
     page: Signal<usize>,
     pages: Signal<BTreeMap<usize, TabInfo>>,
+    latest_versions: Signal<HashMap<String, String>>,
+    /// Rendered alone next to the branch list, so it switches to its own tab instead of hiding
+    #[props(default)]
+    compact: bool,
 }
 
 #[component]
 fn Version(mut props: VersionProps) -> Element {
-    let profile = use_resource(move || {
+    // Older 'modpack_version' selected by the user, 'None' installs the newest one on the branch
+    let mut pinned_version: Signal<Option<String>> = use_signal(|| None);
+    // Provided by 'app' so every branch shares one connection pool, replaced when the settings are saved
+    let http_client = use_context::<Signal<super::CachedHttpClient>>();
+    let versions_source = props.modpack_source.clone();
+    let pinned_versions = use_resource(move || {
+        super::fetch_pinned_versions(versions_source.clone(), http_client())
+    });
+    // MultiMC installs the loader itself, read before 'props' is moved into the resource
+    let vanilla = matches!(props.launcher, super::Launcher::Vanilla(_));
+    let mut profile = use_resource(move || {
         let source = props.modpack_source.clone();
         let branch = props.modpack_branch.clone();
         let launcher = props.launcher.clone();
-        async move { super::init(source, branch, launcher).await }
+        let pinned = pinned_version();
+        let http_client = http_client();
+        async move {
+            match pinned {
+                Some(version) => {
+                    super::init_pinned(source, version, launcher, &http_client).await
+                }
+                None => super::init_shared(source, branch, launcher, &http_client).await,
+            }
+        }
     });
 
     // 'use_future's will always be 'None' on components first render
     if profile.read().is_none() {
         return rsx! {
-            div { class: "container", "Loading..." }
+            div { class: "container", {t("loading")} }
         };
     };
 
     let installer_profile = match profile.unwrap() {
         Ok(v) => v,
+        // Only network errors can go away on their own, everything else needs a fixed manifest or installer
+        Err(super::InstallError::Network(e)) => {
+            return rsx! {
+                div { class: "container",
+                    h1 { {t("app.offline_title")} }
+                    textarea { class: "error-area", readonly: true, "{e}" }
+                    button {
+                        class: "install-button",
+                        onclick: move |evt| {
+                            profile.restart();
+                            evt.stop_propagation();
+                        },
+                        {t("retry")}
+                    }
+                }
+            };
+        }
         Err(e) => {
-            props.error.set(Some(
-                format!("{:#?}", e) + " (Failed to retrieve installer profile!)",
-            ));
+            props.error.set(Some(e.to_string() + " (Failed to retrieve installer profile!)"));
             return None;
         }
     };
 
+    // Lets the installed-packs manager show update status without refetching manifests
+    if props.latest_versions.read().get(&installer_profile.manifest.uuid)
+        != Some(&installer_profile.manifest.modpack_version)
+    {
+        props.latest_versions.with_mut(|x| {
+            x.insert(
+                installer_profile.manifest.uuid.clone(),
+                installer_profile.manifest.modpack_version.clone(),
+            )
+        });
+    }
+
     let tab_group = if let Some(tab_group) = installer_profile.manifest.tab_group {
         tab_group
     } else {
@@ -561,10 +1177,10 @@ fn Version(mut props: VersionProps) -> Element {
 
     let mut installing = use_signal(|| false);
     let mut progress_status = use_signal(|| "");
-    let mut install_progress = use_signal(|| 0);
+    let install_progress = use_signal(|| 0);
     let mut modify = use_signal(|| false);
     let mut modify_count = use_signal(|| 0);
-    let enabled_features = use_signal(|| {
+    let mut enabled_features = use_signal(|| {
         if installer_profile.installed {
             installer_profile
                 .local_manifest
@@ -578,6 +1194,11 @@ fn Version(mut props: VersionProps) -> Element {
     });
     let mut install_item_amount = use_signal(|| 0);
     let mut credits = use_signal(|| false);
+    let mut confirming = use_signal(|| false);
+    let mut precheck_problems: Signal<Option<Vec<String>>> = use_signal(|| None);
+    // Locale key of the finished action, shows 'InstallComplete' while set
+    let mut completed: Signal<Option<&'static str>> = use_signal(|| None);
+    let mut post_install_status: Signal<Option<&'static str>> = use_signal(|| None);
     let mut installed = use_signal(|| installer_profile.installed);
     let mut update_available = use_signal(|| installer_profile.update_available);
     let mut local_features = use_signal(|| {
@@ -587,9 +1208,96 @@ fn Version(mut props: VersionProps) -> Element {
             None
         }
     });
+    // Polls the remote manifest so a new version shows up without restarting the installer
+    let poll_profile = installer_profile.clone();
+    use_future(move || {
+        let poll_profile = poll_profile.clone();
+        async move {
+            let mut known_version = poll_profile.manifest.modpack_version.clone();
+            let mut interval = UPDATE_POLL_INTERVAL;
+            loop {
+                tokio::time::sleep(interval).await;
+                match super::fetch_remote_version(&poll_profile).await {
+                    Ok(version) => {
+                        interval = UPDATE_POLL_INTERVAL;
+                        if version != known_version {
+                            info!("Found new version '{version}' of '{}'", poll_profile.manifest.subtitle);
+                            known_version = version;
+                            // Reloads the profile with the new manifest so updating installs it
+                            profile.restart();
+                            if installed() {
+                                update_available.set(true);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        interval = (interval * 2).min(MAX_UPDATE_POLL_INTERVAL);
+                        warn!(
+                            "Failed to check '{}' for updates, retrying in {}s: {e}",
+                            poll_profile.manifest.subtitle,
+                            interval.as_secs()
+                        );
+                    }
+                }
+            }
+        }
+    });
+    let preset_features = installer_profile.manifest.features.clone();
+    let feature_groups = group_features(&installer_profile.manifest.features);
+    let show_categories = feature_groups.iter().any(|(category, _)| category.is_some());
+    let mut collapsed_categories: Signal<Vec<Option<String>>> = use_signal(Vec::new);
+    let mut share_code = use_signal(String::new);
+    let on_copy_selection = move |evt: MouseEvent| {
+        let code = super::encode_feature_code(&enabled_features.read());
+        // Serializing to JSON gives us a correctly escaped JS string literal
+        let _ = eval(&format!(
+            "navigator.clipboard.writeText({});",
+            serde_json::to_string(&code).unwrap()
+        ));
+        evt.stop_propagation();
+    };
+    let share_manifest = installer_profile.manifest.clone();
+    let on_paste_selection = move |evt: MouseEvent| {
+        let manifest = share_manifest.clone();
+        share_code.set(String::new());
+        use_context::<ModalContext>().open(
+            t("version.paste_selection_title"),
+            rsx!(input {
+                class: "proxy-input",
+                r#type: "text",
+                placeholder: t("version.paste_selection_placeholder"),
+                oninput: move |evt| share_code.set(evt.value())
+            }),
+            true,
+            Some(move |canceled| {
+                if canceled {
+                    return;
+                }
+                match super::decode_feature_code(&share_code.read(), &manifest) {
+                    // Applied like a preset so hidden features stay as they are
+                    Ok(features) => apply_preset(
+                        local_features,
+                        modify,
+                        &super::Preset {
+                            name: String::new(),
+                            features,
+                        },
+                        &manifest.features,
+                        modify_count,
+                        enabled_features,
+                    ),
+                    Err(e) => props
+                        .error
+                        .set(Some(e + " (Failed to paste feature selection!)")),
+                }
+            }),
+        );
+        evt.stop_propagation();
+    };
+    let cancelled = installer_profile.cancelled.clone();
     let movable_profile = installer_profile.clone();
-    let on_submit = move |_| {
-        // TODO: Don't do naive item amount calculation
+    let mut start_install = move |_| {
+        // Estimate until 'InstallEvent::Started' reports the actual amount
         *install_item_amount.write() = movable_profile.manifest.mods.len()
             + movable_profile.manifest.resourcepacks.len()
             + movable_profile.manifest.shaderpacks.len()
@@ -604,18 +1312,19 @@ fn Version(mut props: VersionProps) -> Element {
                         return;
                     }
                     installing.set(true);
+                    installer_profile.cancelled.store(false, Ordering::Relaxed);
                     installer_profile.enabled_features = enabled_features.read().clone();
                     installer_profile.manifest.enabled_features = enabled_features.read().clone();
                     local_features.set(Some(enabled_features.read().clone()));
+                    track_progress(&mut installer_profile, install_progress, install_item_amount);
 
+                    let mut report = super::InstallReport::default();
                     if !*installed.read() {
-                        progress_status.set("Installing");
-                        match super::install(&installer_profile, move || {
-                            install_progress.with_mut(|x| *x += 1);
-                        })
-                        .await
+                        progress_status.set("progress.installing");
+                        match super::install(&installer_profile, super::Side::Client, || {}).await
                         {
-                            Ok(_) => {
+                            Ok(v) => {
+                                report = v;
                                 let _ = isahc::post(
                                     "https://tracking.commander07.workers.dev/track",
                                     format!(
@@ -637,22 +1346,23 @@ fn Version(mut props: VersionProps) -> Element {
                                 );
                             }
                             Err(e) => {
-                                props.error.set(Some(
-                                    format!("{:#?}", e) + " (Failed to install modpack!)",
-                                ));
+                                if !installer_profile.cancelled.load(Ordering::Relaxed) {
+                                    props.error.set(Some(
+                                        e.to_string() + " (Failed to install modpack!)",
+                                    ));
+                                }
                                 installing.set(false);
                                 return;
                             }
                         }
                         installed.set(true);
+                        completed.set(Some("complete.installed"));
                     } else if *update_available.read() {
-                        progress_status.set("Updating");
-                        match super::update(&installer_profile, move || {
-                            install_progress.with_mut(|x| *x += 1);
-                        })
-                        .await
+                        progress_status.set("progress.updating");
+                        match super::update(&installer_profile, super::Side::Client, || {}).await
                         {
-                            Ok(_) => {
+                            Ok(v) => {
+                                report = v;
                                 let _ = isahc::post(
                                     "https://tracking.commander07.workers.dev/track",
                                     format!(
@@ -672,22 +1382,23 @@ fn Version(mut props: VersionProps) -> Element {
                                 );
                             }
                             Err(e) => {
-                                props.error.set(Some(
-                                    format!("{:#?}", e) + " (Failed to update modpack!)",
-                                ));
+                                if !installer_profile.cancelled.load(Ordering::Relaxed) {
+                                    props.error.set(Some(
+                                        e.to_string() + " (Failed to update modpack!)",
+                                    ));
+                                }
                                 installing.set(false);
                                 return;
                             }
                         }
                         update_available.set(false);
+                        completed.set(Some("complete.updated"));
                     } else if *modify.read() {
-                        progress_status.set("Modifying");
-                        match super::update(&installer_profile, move || {
-                            *install_progress.write() += 1
-                        })
-                        .await
+                        progress_status.set("progress.modifying");
+                        match super::update(&installer_profile, super::Side::Client, || {}).await
                         {
-                            Ok(_) => {
+                            Ok(v) => {
+                                report = v;
                                 let _ = isahc::post(
                                     "https://tracking.commander07.workers.dev/track",
                                     format!(
@@ -705,9 +1416,11 @@ fn Version(mut props: VersionProps) -> Element {
                                 );
                             }
                             Err(e) => {
-                                props.error.set(Some(
-                                    format!("{:#?}", e) + " (Failed to modify modpack!)",
-                                ));
+                                if !installer_profile.cancelled.load(Ordering::Relaxed) {
+                                    props.error.set(Some(
+                                        e.to_string() + " (Failed to modify modpack!)",
+                                    ));
+                                }
                                 installing.set(false);
                                 return;
                             }
@@ -715,12 +1428,54 @@ fn Version(mut props: VersionProps) -> Element {
                         modify.with_mut(|x| *x = false);
                         modify_count.with_mut(|x| *x = 0);
                         update_available.set(false);
+                        completed.set(Some("complete.modified"));
+                    }
+                    let skipped = report.skipped;
+                    // Only one modal can be open at a time so the memory adjustments are appended to the skipped items
+                    let memory_adjustments = report.memory_adjustments;
+                    if !skipped.is_empty() {
+                        consume_context::<ModalContext>().open(
+                            t("version.skipped_title"),
+                            rsx!(
+                                p { {t("version.skipped_text")} }
+                                ul {
+                                    for name in skipped {
+                                        li { "{name}" }
+                                    }
+                                }
+                                if !memory_adjustments.is_empty() {
+                                    p { {t("version.memory_adjusted_text")} }
+                                    ul {
+                                        for adjustment in memory_adjustments {
+                                            li { "{adjustment}" }
+                                        }
+                                    }
+                                }
+                            ),
+                            false,
+                            None::<fn(bool)>,
+                        );
+                    } else if !memory_adjustments.is_empty() {
+                        consume_context::<ModalContext>().open(
+                            t("version.memory_adjusted_title"),
+                            rsx!(
+                                p { {t("version.memory_adjusted_text")} }
+                                ul {
+                                    for adjustment in memory_adjustments {
+                                        li { "{adjustment}" }
+                                    }
+                                }
+                            ),
+                            false,
+                            None::<fn(bool)>,
+                        );
                     }
                     installing.set(false);
                 });
             };
 
-            if let Some(contents) = movable_profile2.manifest.popup_contents {
+            if let Some(contents) = movable_profile2.manifest.popup_contents.as_deref() {
+                let contents = movable_profile2.manifest_html(contents);
                 use_context::<ModalContext>().open(
                     movable_profile2.manifest.popup_title.unwrap_or_default(),
                     rsx!(div {
@@ -735,6 +1490,197 @@ fn Version(mut props: VersionProps) -> Element {
         }
     };
 
+    let reinstall_profile = installer_profile.clone();
+    let mut reinstall_loader = use_signal(|| false);
+    let on_reinstall = move |evt: MouseEvent| {
+        let reinstall_profile = reinstall_profile.clone();
+        reinstall_loader.set(false);
+        use_context::<ModalContext>().open(
+            t("version.reinstall_title"),
+            rsx!(
+                p { {t("version.reinstall_text")} }
+                if vanilla {
+                    label { class: "tooltip",
+                        input {
+                            r#type: "checkbox",
+                            onchange: move |evt| reinstall_loader.set(evt.value() == "true")
+                        }
+                        {t("version.reinstall_loader")}
+                        span { class: "tooltiptext", {t("version.reinstall_loader_tooltip")} }
+                    }
+                }
+            ),
+            true,
+            Some(move |canceled| {
+                if canceled {
+                    return;
+                }
+                let mut installer_profile = reinstall_profile.clone();
+                installer_profile.force_loader = reinstall_loader();
+                spawn(async move {
+                    *install_item_amount.write() = installer_profile.manifest.mods.len()
+                        + installer_profile.manifest.resourcepacks.len()
+                        + installer_profile.manifest.shaderpacks.len()
+                        + installer_profile.manifest.include.len();
+                    track_progress(&mut installer_profile, install_progress, install_item_amount);
+                    installing.set(true);
+                    installer_profile.cancelled.store(false, Ordering::Relaxed);
+                    progress_status.set("progress.reinstalling");
+                    if let Err(e) = super::reinstall(&installer_profile, super::Side::Client, || {}).await {
+                        if !installer_profile.cancelled.load(Ordering::Relaxed) {
+                            props.error.set(Some(
+                                e.to_string() + " (Failed to reinstall modpack!)",
+                            ));
+                        }
+                        installing.set(false);
+                        return;
+                    }
+                    installed.set(true);
+                    update_available.set(false);
+                    // Unapplied checkbox changes are dropped as the previously enabled features were reinstalled
+                    if let Some(features) = local_features() {
+                        enabled_features.set(features);
+                    }
+                    modify.set(false);
+                    modify_count.set(0);
+                    completed.set(Some("complete.reinstalled"));
+                    installing.set(false);
+                });
+            }),
+        );
+        evt.stop_propagation();
+    };
+
+    let cleanup_profile = installer_profile.clone();
+    let on_cleanup = move |evt: MouseEvent| {
+        let profile = cleanup_profile.clone();
+        let files = match super::find_unmanaged_files(&profile) {
+            Ok(v) => v,
+            Err(e) => {
+                props.error.set(Some(e.to_string() + " (Failed to find unmanaged files!)"));
+                return;
+            }
+        };
+        if files.is_empty() {
+            use_context::<ModalContext>().open(
+                t("version.cleanup_title"),
+                rsx!(p { {t("version.cleanup_none")} }),
+                false,
+                None::<fn(bool)>,
+            );
+        } else {
+            let listed = files.clone();
+            use_context::<ModalContext>().open(
+                t("version.cleanup_title"),
+                rsx!(
+                    p { {t("version.cleanup_text")} }
+                    ul {
+                        for file in listed {
+                            li { {file.to_string_lossy().to_string()} }
+                        }
+                    }
+                ),
+                true,
+                Some(move |canceled| {
+                    if canceled {
+                        return;
+                    }
+                    if let Err(e) = super::remove_unmanaged_files(&profile, &files) {
+                        props.error.set(Some(e.to_string() + " (Failed to remove unmanaged files!)"));
+                    }
+                }),
+            );
+        }
+        evt.stop_propagation();
+    };
+
+    let export_profile = installer_profile.clone();
+    let on_export = move |evt: MouseEvent| {
+        let dest = rfd::FileDialog::new()
+            .set_title(t("version.export_dialog"))
+            .set_file_name(format!("{}.zip", export_profile.manifest.subtitle))
+            .add_filter("zip", &["zip"])
+            .save_file();
+        if let Some(dest) = dest {
+            if let Err(e) = super::export_pack(&export_profile, &dest) {
+                props.error.set(Some(e.to_string() + " (Failed to export modpack!)"));
+            }
+        }
+        evt.stop_propagation();
+    };
+
+    let precheck_profile = installer_profile.clone();
+    let on_submit = move |_| {
+        confirming.set(true);
+        precheck_problems.set(None);
+        let mut profile = precheck_profile.clone();
+        profile.enabled_features = enabled_features();
+        spawn(async move {
+            // A check that failed as a whole is listed like a problem so installing anyway stays possible
+            let problems = match super::precheck(&profile).await {
+                Ok(v) => v,
+                Err(e) => vec![e.to_string()],
+            };
+            precheck_problems.set(Some(problems));
+        });
+    };
+    let summary_features: Vec<String> = installer_profile
+        .manifest
+        .features
+        .iter()
+        .filter(|x| !x.hidden && enabled_features.read().contains(&x.id))
+        .map(|x| x.name.clone())
+        .collect();
+    let mut summary_profile = installer_profile.clone();
+    summary_profile.enabled_features = enabled_features();
+    let summary_downloads = super::count_install_items(&summary_profile);
+    // Computed before 'update' touches anything so the user knows what they're agreeing to
+    let summary_changes = if *update_available.read() {
+        summary_profile.update_changes()
+    } else {
+        None
+    };
+    let summary_directory = match &installer_profile.launcher {
+        Some(launcher) => super::get_modpack_dir(launcher, &installer_profile.manifest.uuid)
+            .display()
+            .to_string(),
+        None => String::new(),
+    };
+    let loader = &installer_profile.manifest.loader;
+    let summary_loader = format!(
+        "{} {} (Minecraft {})",
+        loader.r#type, loader.version, loader.minecraft_version
+    );
+
+    let launch_instructions = match &installer_profile.launcher {
+        Some(Launcher::Vanilla(_)) => t("complete.launch_vanilla"),
+        _ => t("complete.launch_multimc"),
+    }
+    .replace("{name}", &installer_profile.manifest.name);
+    let post_install_profile = installer_profile.clone();
+    let on_run_post_install = move |_| {
+        let profile = post_install_profile.clone();
+        post_install_status.set(Some("complete.post_install_running"));
+        spawn(async move {
+            match super::run_post_install_command(&profile).await {
+                Ok(_) => post_install_status.set(Some("complete.post_install_done")),
+                Err(e) => {
+                    post_install_status.set(None);
+                    props
+                        .error
+                        .set(Some(e.to_string() + " (Failed to run post install command!)"));
+                }
+            }
+        });
+    };
+
+    // Releases are optional so failing to list them just hides the version selection
+    let versions = pinned_versions
+        .read()
+        .clone()
+        .and_then(|x| x.ok())
+        .unwrap_or_default();
+
     let install_disable = if *installed.read() && !*update_available.read() && !*modify.read() {
         Some("true")
     } else {
@@ -744,7 +1690,9 @@ fn Version(mut props: VersionProps) -> Element {
     if *props.name.read() == String::default() {
         props.name.set(installer_profile.manifest.name.clone())
     }
-    if (props.page)() != tab_group {
+    if props.compact && (props.page)() != tab_group {
+        props.page.set(tab_group);
+    } else if (props.page)() != tab_group {
         return None;
     }
     rsx! {
@@ -753,11 +1701,44 @@ fn Version(mut props: VersionProps) -> Element {
                 value: install_progress(),
                 max: install_item_amount() as i64,
                 title: installer_profile.manifest.subtitle,
-                status: progress_status.to_string()
+                status: t(progress_status()),
+                on_cancel: move |_| {
+                    cancelled.store(true, Ordering::Relaxed);
+                    progress_status.set("progress.cancelling");
+                }
+            }
+        } else if completed.read().is_some() {
+            InstallComplete {
+                title: installer_profile.manifest.subtitle.clone(),
+                status: t(completed().unwrap_or_default()),
+                launch: launch_instructions,
+                changelog: installer_profile.manifest.changelog.clone(),
+                post_install_command: installer_profile.manifest.post_install_command.clone(),
+                post_install_status: post_install_status(),
+                on_run_post_install: on_run_post_install,
+                on_done: move |_| {
+                    completed.set(None);
+                    post_install_status.set(None);
+                }
+            }
+        } else if *confirming.read() {
+            InstallSummary {
+                title: installer_profile.manifest.subtitle.clone(),
+                features: summary_features,
+                loader: summary_loader,
+                downloads: summary_downloads,
+                directory: summary_directory,
+                changes: summary_changes,
+                problems: precheck_problems(),
+                on_confirm: move |_| {
+                    confirming.set(false);
+                    spawn(start_install(()));
+                },
+                on_back: move |_| confirming.set(false)
             }
         } else if *credits.read() {
             Credits {
-                manifest: installer_profile.manifest,
+                manifest: installer_profile.credits_manifest(),
                 enabled: installer_profile.enabled_features,
                 credits
             }
@@ -782,42 +1763,129 @@ fn Version(mut props: VersionProps) -> Element {
                             div { style: "width: 21vw",
                                 div {
                                     class: "description",
-                                    dangerous_inner_html: "{installer_profile.manifest.description}"
+                                    dangerous_inner_html: "{installer_profile.manifest_html(&installer_profile.manifest.description)}"
                                 }
                                 p { style: "font-size: 1.2em;margin-bottom: .5em;",
-                                    "Optional features:"
+                                    {t("version.optional_features")}
                                 }
-                                div { class: "feature-list",
-                                    for feat in installer_profile.manifest.features {
-                                        if !feat.hidden {
-                                            label { class: "tooltip",
-                                                input {
-                                                    checked: if installer_profile.installed {
-                                                        if enabled_features.with(|x| x.contains(&feat.id)) { Some("true") } else { None }
-                                                    } else {
-                                                        if feat.default { Some("true") } else { None }
-                                                    },
-                                                    name: "{feat.id}",
-                                                    onchange: move |evt| {
-                                                        feature_change(
-                                                            local_features,
-                                                            modify,
-                                                            evt,
-                                                            &feat,
-                                                            modify_count,
-                                                            enabled_features,
-                                                        )
-                                                    },
-                                                    r#type: "checkbox"
+                                if !versions.is_empty() {
+                                    div { class: "label",
+                                        span { {t("version.pinned")} }
+                                        select {
+                                            class: "credits-button preset-select",
+                                            onchange: move |evt| {
+                                                let value = evt.data.value();
+                                                pinned_version.set(if value.is_empty() { None } else { Some(value) });
+                                                // Installing another version over the installed one goes through 'update'
+                                                if installed() {
+                                                    update_available.set(true);
                                                 }
+                                            },
+                                            option {
+                                                value: "",
+                                                selected: pinned_version.read().is_none(),
+                                                {t("version.latest")}
+                                            }
+                                            for version in versions {
+                                                option {
+                                                    value: "{version}",
+                                                    selected: pinned_version.read().as_deref() == Some(version.as_str()),
+                                                    "{version}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                if let Some(presets) = installer_profile.manifest.presets.clone() {
+                                    div { class: "label",
+                                        span { {t("version.preset")} }
+                                        select {
+                                            class: "credits-button preset-select",
+                                            onchange: move |evt| {
+                                                if let Ok(i) = evt.data.value().parse::<usize>() {
+                                                    apply_preset(
+                                                        local_features,
+                                                        modify,
+                                                        &presets[i],
+                                                        &preset_features,
+                                                        modify_count,
+                                                        enabled_features,
+                                                    )
+                                                }
+                                            },
+                                            option { value: "", {t("version.preset_custom")} }
+                                            for (i, preset) in installer_profile.manifest.presets.iter().flatten().enumerate() {
+                                                option { value: "{i}", "{preset.name}" }
+                                            }
+                                        }
+                                    }
+                                }
+                                div { class: "label share-buttons",
+                                    button {
+                                        class: "credits-button",
+                                        r#type: "button",
+                                        onclick: on_copy_selection,
+                                        {t("version.copy_selection")}
+                                    }
+                                    button {
+                                        class: "credits-button",
+                                        r#type: "button",
+                                        onclick: on_paste_selection,
+                                        {t("version.paste_selection")}
+                                    }
+                                }
+                                div { class: "feature-list",
+                                    for (category, feats) in feature_groups {
+                                        if show_categories {
+                                            button {
+                                                class: "category-header",
+                                                r#type: "button",
+                                                onclick: {
+                                                    let category = category.clone();
+                                                    move |_| {
+                                                        collapsed_categories.with_mut(|x| {
+                                                            if x.contains(&category) {
+                                                                x.retain(|c| c != &category);
+                                                            } else {
+                                                                x.push(category.clone());
+                                                            }
+                                                        })
+                                                    }
+                                                },
+                                                if collapsed_categories.read().contains(&category) { "▸ " } else { "▾ " }
+                                                {category.clone().unwrap_or_else(|| t("version.category_default"))}
+                                            }
+                                        }
+                                        if !collapsed_categories.read().contains(&category) {
+                                            for feat in feats {
+                                                label { class: "tooltip",
+                                                    input {
+                                                        checked: if enabled_features.with(|x| x.contains(&feat.id)) { Some("true") } else { None },
+                                                        name: "{feat.id}",
+                                                        onchange: move |evt| {
+                                                            feature_change(
+                                                                local_features,
+                                                                modify,
+                                                                evt,
+                                                                &feat,
+                                                                modify_count,
+                                                                enabled_features,
+                                                            )
+                                                        },
+                                                        r#type: "checkbox"
+                                                    }
 
-                                                "{feat.name}"
-                                                match feat.description {
-                                                    Some(ref desc) => rsx!(span {
-                                                        class: "tooltiptext",
-                                                        "{desc}",
-                                                    }),
-                                                    None => rsx!("")
+                                                    "{feat.name}"
+                                                    if !feat.supports_current_os() {
+                                                        {format!(" ({})", t("version.unsupported_os"))}
+                                                    }
+                                                    match feat.description {
+                                                        Some(ref desc) => rsx!(span {
+                                                            class: "tooltiptext",
+                                                            "{desc}",
+                                                        }),
+                                                        None => rsx!("")
+                                                    }
                                                 }
                                             }
                                         }
@@ -828,13 +1896,33 @@ fn Version(mut props: VersionProps) -> Element {
                         input {
                             r#type: "submit",
                             value: if !installer_profile.installed {
-                                "Install"
+                                t("version.install")
                             } else {
-                                if !*modify.read() { "Update" } else { "Modify" }
+                                if !*modify.read() { t("version.update") } else { t("version.modify") }
                             },
                             class: "install-button",
                             disabled: install_disable
                         }
+                        if *installed.read() {
+                            button {
+                                class: "uninstall-button",
+                                r#type: "button",
+                                onclick: on_reinstall,
+                                {t("version.reinstall")}
+                            }
+                            button {
+                                class: "uninstall-button",
+                                r#type: "button",
+                                onclick: on_export,
+                                {t("version.export")}
+                            }
+                            button {
+                                class: "uninstall-button",
+                                r#type: "button",
+                                onclick: on_cleanup,
+                                {t("version.cleanup")}
+                            }
+                        }
                     }
                 }
             }
@@ -863,7 +1951,6 @@ fn Pagination(mut page: Signal<usize>, mut pages: Signal<BTreeMap<usize, TabInfo
 
 #[derive(Clone)]
 pub(crate) struct AppProps {
-    pub branches: Vec<super::GithubBranch>,
     pub modpack_source: String,
     pub config: super::Config,
     pub config_path: PathBuf,
@@ -872,15 +1959,34 @@ pub(crate) struct AppProps {
 pub(crate) fn app() -> Element {
     let props = use_context::<AppProps>();
     let css = include_str!("assets/style.css");
-    let branches = props.branches;
+    use_wry_event_handler(|event, _| {
+        if let Event::WindowEvent {
+            event: WindowEvent::CloseRequested,
+            ..
+        } = event
+        {
+            super::flush_store(super::STORE_FLUSH_TIMEOUT);
+        }
+    });
+    use_context_provider(|| Signal::new(super::CachedHttpClient::new()));
+    // Replaced by a modpack the user opened, 'props.modpack_source' stays the default
+    let mut modpack_source = use_signal(|| props.modpack_source.clone());
+    // Fetched after the window is open so a slow or missing connection doesn't block startup
+    let mut branches = use_resource(move || super::fetch_branches(modpack_source()));
+    let mut source_input = use_signal(String::new);
     let config = use_signal(|| props.config);
     let mut settings = use_signal(|| false);
+    let mut manager = use_signal(|| false);
+    let mut browse = use_signal(|| false);
+    let latest_versions = use_signal(HashMap::<String, String>::new);
     let mut err: Signal<Option<String>> = use_signal(|| None);
 
     let name = use_signal(String::default);
 
-    let page = use_signal(|| 0);
-    let pages = use_signal(|| BTreeMap::<usize, TabInfo>::new());
+    let mut page = use_signal(|| 0);
+    let mut pages = use_signal(|| BTreeMap::<usize, TabInfo>::new());
+    // Branch shown in the compact view, 'None' shows the first listed one
+    let mut selected_branch: Signal<Option<String>> = use_signal(|| None);
     let css = css
         .replace(
             "<BG_COLOR>",
@@ -910,17 +2016,51 @@ pub(crate) fn app() -> Element {
         });
 
     let cfg = config.with(|cfg| cfg.clone());
+    i18n::set_locale(cfg.locale.as_deref());
+    // WIP branches are only listed when beta versions are enabled in the settings
+    let show_branch =
+        |name: &str| cfg.show_beta.unwrap_or(false) || super::is_release_branch(&cfg, name);
+    let compact = cfg.compact_view.unwrap_or(false);
     let launcher = match super::get_launcher(&cfg.launcher) {
         Ok(val) => Some(val),
         Err(_) => None,
     };
 
     let mut modal_context = use_context_provider(|| ModalContext::default());
+    let mut source_modal = modal_context.clone();
+    let on_open_source = move |evt: MouseEvent| {
+        source_input.set(String::new());
+        source_modal.open(
+            t("app.open_source_title"),
+            rsx!(input {
+                class: "proxy-input",
+                r#type: "text",
+                placeholder: t("app.open_source_placeholder"),
+                oninput: move |evt| source_input.set(evt.value())
+            }),
+            true,
+            Some(move |canceled| {
+                if canceled {
+                    return;
+                }
+                match super::parse_modpack_source(&source_input.read()) {
+                    Ok(source) if source != modpack_source() => {
+                        // Tabs of the previous modpack would otherwise stay visible
+                        pages.set(BTreeMap::new());
+                        page.set(0);
+                        selected_branch.set(None);
+                        modpack_source.set(source);
+                    }
+                    Ok(_) => {}
+                    Err(e) => err.set(Some(e + " (Failed to open modpack!)")),
+                }
+            }),
+        );
+        evt.stop_propagation();
+    };
     if let Some(e) = err() {
-        modal_context.open("Error", rsx! {
-            p {
-                "The installer encountered an error if the problem does not resolve itself please open a thread in #📂modpack-issues on the discord."
-            }
+        modal_context.open(t("app.error"), rsx! {
+            p { {t("app.error_text")} }
             textarea { class: "error-area", readonly: true, "{e}" }
         }, false, Some(move |_| err.set(None)));
     }
@@ -941,7 +2081,60 @@ pub(crate) fn app() -> Element {
                     b64_id: engine::general_purpose::URL_SAFE_NO_PAD.encode(props.modpack_source)
                 }
             }
+        } else if *manager.read() {
+            div { class: "toolbar",
+                button {
+                    class: "toolbar-button",
+                    onclick: move |evt| {
+                        manager.set(false);
+                        evt.stop_propagation();
+                    },
+                    {t("back")}
+                }
+            }
+            div { class: "fake-body",
+                PackManager { config, latest_versions, error: err }
+            }
+        } else if (config.read().first_launch.unwrap_or(true) || launcher.is_none()) && *browse.read() {
+            div { class: "toolbar" }
+            div { class: "fake-body",
+                match branches() {
+                    Some(Ok(branches)) => match branches.into_iter().find(|x| show_branch(&x.name)) {
+                        Some(branch) => rsx!(Preview {
+                            modpack_source: modpack_source(),
+                            modpack_branch: branch.name,
+                            browse,
+                            error: err
+                        }),
+                        None => rsx!(div { class: "container", {t("app.no_versions")} }),
+                    },
+                    Some(Err(_)) => rsx!(
+                        div { class: "container",
+                            {t("app.offline_title")}
+                            button {
+                                class: "install-button",
+                                onclick: move |evt| {
+                                    branches.restart();
+                                    evt.stop_propagation();
+                                },
+                                {t("retry")}
+                            }
+                        }
+                    ),
+                    None => rsx!(div { class: "container", {t("loading")} }),
+                }
+            }
         } else if config.read().first_launch.unwrap_or(true) || launcher.is_none() {
+            div { class: "toolbar",
+                button {
+                    class: "toolbar-button",
+                    onclick: move |evt| {
+                        browse.set(true);
+                        evt.stop_propagation();
+                    },
+                    {t("app.browse")}
+                }
+            }
             div { class: "fake-body",
                 Launcher {
                     config,
@@ -952,7 +2145,19 @@ pub(crate) fn app() -> Element {
             }
         } else {
             div { class: "toolbar",
-                Pagination { page, pages }
+                // The branch list replaces the tabs in the compact view
+                if !compact {
+                    Pagination { page, pages }
+                }
+                button { class: "toolbar-button", onclick: on_open_source, {t("app.open_source")} }
+                button {
+                    class: "toolbar-button",
+                    onclick: move |evt| {
+                        manager.set(true);
+                        evt.stop_propagation();
+                    },
+                    {t("app.installed")}
+                }
                 button {
                     class: "toolbar-button",
                     style: "padding: 0;margin-right: 0;",
@@ -964,16 +2169,82 @@ pub(crate) fn app() -> Element {
                 }
             }
             div { class: "fake-body",
-                for i in 0..branches.len() {
-                    Version {
-                        modpack_source: props.modpack_source.clone(),
-                        modpack_branch: branches[i].name.clone(),
-                        launcher: launcher.as_ref().unwrap().clone(),
-                        error: err,
-                        name,
-                        page,
-                        pages
+                match branches() {
+                    None => rsx!(div { class: "container", {t("loading")} }),
+                    Some(Err(e)) => rsx!(
+                        div { class: "container",
+                            h1 { {t("app.offline_title")} }
+                            p { {t("app.offline_text")} }
+                            textarea { class: "error-area", readonly: true, "{e}" }
+                            button {
+                                class: "install-button",
+                                onclick: move |evt| {
+                                    branches.restart();
+                                    evt.stop_propagation();
+                                },
+                                {t("retry")}
+                            }
+                        }
+                    ),
+                    Some(Ok(branches)) if compact => {
+                        let branches = branches
+                            .into_iter()
+                            .filter(|x| show_branch(&x.name))
+                            .map(|x| x.name)
+                            .collect::<Vec<_>>();
+                        let selected = selected_branch()
+                            .filter(|x| branches.contains(x))
+                            .or_else(|| branches.first().cloned());
+                        rsx!(
+                            div { class: "container branch-list",
+                                for branch in branches {
+                                    button {
+                                        class: "toolbar-button branch-button",
+                                        disabled: selected.as_ref() == Some(&branch),
+                                        onclick: {
+                                            let branch = branch.clone();
+                                            move |evt: MouseEvent| {
+                                                selected_branch.set(Some(branch.clone()));
+                                                evt.stop_propagation();
+                                            }
+                                        },
+                                        "{branch}"
+                                    }
+                                }
+                            }
+                            if let Some(branch) = selected {
+                                Version {
+                                    key: "{modpack_source}{branch}",
+                                    modpack_source: modpack_source(),
+                                    modpack_branch: branch,
+                                    launcher: launcher.as_ref().unwrap().clone(),
+                                    error: err,
+                                    name,
+                                    page,
+                                    pages,
+                                    latest_versions,
+                                    compact: true
+                                }
+                            } else {
+                                div { class: "container", {t("app.no_versions")} }
+                            }
+                        )
                     }
+                    Some(Ok(branches)) => rsx!(
+                        for branch in branches.into_iter().filter(|x| show_branch(&x.name)) {
+                            Version {
+                                key: "{modpack_source}{branch.name}",
+                                modpack_source: modpack_source(),
+                                modpack_branch: branch.name,
+                                launcher: launcher.as_ref().unwrap().clone(),
+                                error: err,
+                                name,
+                                page,
+                                pages,
+                                latest_versions
+                            }
+                        }
+                    ),
                 }
             }
         }
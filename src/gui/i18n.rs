@@ -0,0 +1,73 @@
+use std::{
+    collections::HashMap,
+    env,
+    sync::{OnceLock, RwLock},
+};
+
+/// Locale codes and their embedded translations. Keys missing from a locale fall back to English.
+const LOCALES: [(&str, &str); 2] = [
+    ("en", include_str!("../assets/locales/en.json")),
+    ("de", include_str!("../assets/locales/de.json")),
+];
+const FALLBACK: &str = "en";
+
+static ACTIVE: RwLock<String> = RwLock::new(String::new());
+
+fn locales() -> &'static HashMap<&'static str, HashMap<String, String>> {
+    static PARSED: OnceLock<HashMap<&'static str, HashMap<String, String>>> = OnceLock::new();
+    PARSED.get_or_init(|| {
+        LOCALES
+            .iter()
+            .map(|(code, json)| {
+                (
+                    *code,
+                    serde_json::from_str(json).expect("Invalid embedded locale!"),
+                )
+            })
+            .collect()
+    })
+}
+
+/// Returns the language part of the OS locale, e.g. 'de' for 'de_DE.UTF-8'
+fn os_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .find_map(|var| env::var(var).ok().filter(|v| !v.is_empty()))
+        .map(|v| {
+            v.split(['_', '-', '.'])
+                .next()
+                .unwrap_or_default()
+                .to_lowercase()
+        })
+}
+
+/// Selects the active locale, 'None' uses the OS locale. Unknown locales fall back to English.
+pub fn set_locale(locale: Option<&str>) {
+    let code = match locale {
+        Some(v) => Some(v.to_string()),
+        None => os_locale(),
+    }
+    .filter(|v| locales().contains_key(v.as_str()))
+    .unwrap_or_else(|| String::from(FALLBACK));
+    if *ACTIVE.read().unwrap() != code {
+        *ACTIVE.write().unwrap() = code;
+    }
+}
+
+/// Returns the code and display name of every embedded locale
+pub fn available() -> Vec<(&'static str, String)> {
+    LOCALES
+        .iter()
+        .map(|(code, _)| (*code, locales()[code]["language.name"].clone()))
+        .collect()
+}
+
+/// Translates 'key' into the active locale, falling back to English and then to the key itself
+pub fn t(key: &str) -> String {
+    let active = ACTIVE.read().unwrap();
+    [active.as_str(), FALLBACK]
+        .iter()
+        .find_map(|code| locales().get(code)?.get(key))
+        .cloned()
+        .unwrap_or_else(|| key.to_string())
+}
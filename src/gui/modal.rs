@@ -4,6 +4,8 @@ use std::fmt::Debug;
 use dioxus::prelude::*;
 use log::warn;
 
+use super::i18n::t;
+
 #[derive(Default)]
 struct ModalInfo {
     title: String,
@@ -113,9 +115,9 @@ pub fn Modal() -> Element {
                     },
                     autofocus: true,
                     if modal.inner.read().cancelable {
-                        "Continue"
+                        {t("continue")}
                     } else {
-                        "Close"
+                        {t("close")}
                     }
                 }
                 if modal.inner.read().cancelable {
@@ -126,7 +128,7 @@ pub fn Modal() -> Element {
                             move |_| modal.cancel()
                         },
                         autofocus: true,
-                        "Cancel"
+                        {t("cancel")}
                     }
                 }
             }
@@ -0,0 +1,7143 @@
+use async_trait::async_trait;
+use base64::{engine, Engine};
+use cached::proc_macro::cached;
+use cached::{Cached, SizedCache};
+use chrono::{DateTime, Utc};
+use dioxus::desktop::tao::window::Icon;
+use dioxus::prelude::LaunchBuilder;
+use dioxus::desktop::{Config as DioxusConfig, LogicalSize, WindowBuilder};
+use futures::channel::oneshot;
+use futures::StreamExt;
+use image::io::Reader as ImageReader;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat};
+use isahc::config::{CaCertificate, RedirectPolicy, SslOption};
+use isahc::http::{HeaderMap, HeaderValue, StatusCode, Uri};
+use isahc::prelude::Configurable;
+use isahc::{AsyncBody, AsyncReadResponseExt, HttpClient, Request, Response};
+use log::{error, info, warn};
+use platform_info::{PlatformInfo, PlatformInfoAPI, UNameAPI};
+use regex::Regex;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use sha2::{Digest, Sha256};
+use simplelog::{
+    ColorChoice, CombinedLogger, Config as LogConfig, LevelFilter, TermLogger, TerminalMode,
+    WriteLogger,
+};
+use sysinfo::{MemoryRefreshKind, RefreshKind, System};
+use tokio::sync::mpsc;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display};
+use std::fs::File;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::Duration;
+use std::{backtrace::Backtrace, panic};
+use std::{
+    env, fs,
+    io::{Cursor, Write},
+    path::{Path, PathBuf},
+    time::{Instant, SystemTime},
+};
+
+mod gui;
+
+const CURRENT_MANIFEST_VERSION: i32 = 3;
+const CONCURRENCY: usize = 14;
+const ATTEMPTS: usize = 3;
+const WAIT_BETWEEN_ATTEMPTS: Duration = Duration::from_secs(20);
+const REPO: &str = "Wynncraft-Overhaul/majestic-overhaul/";
+/// The public GitHub API, the only host 'GITHUB_TOKEN' is sent to
+const GITHUB_API: &str = "https://api.github.com/repos/";
+/// Bounds of the max memory computed from the system RAM, see 'get_system_max_mem'
+const MIN_DEFAULT_MAX_MEM: u64 = 1024;
+const MAX_DEFAULT_MAX_MEM: u64 = 8192;
+/// Share of the physical RAM (in percent) a modpack may use at most, see 'clamp_memory'
+const MAX_MEM_RAM_PERCENT: u64 = 75;
+/// Loader version which is resolved to the newest stable loader on install, see 'resolve_loader'
+const LATEST_LOADER: &str = "latest";
+/// Waits before retrying when Mediafire serves its captcha page, see 'get_mediafire_ddl'
+const MEDIAFIRE_BACKOFF: [Duration; 2] = [Duration::from_secs(10), Duration::from_secs(30)];
+/// Lowercase snippets only found on Mediafire's captcha page
+const MEDIAFIRE_INTERSTITIAL_MARKERS: [&str; 2] = ["g-recaptcha", "captcha-form"];
+/// Larger responses aren't kept in the in-memory cache of 'get_cached'
+const MAX_CACHED_RESPONSE_SIZE: usize = 1024 * 1024;
+/// Counting an install is best effort, see 'ping_install_count'
+const INSTALL_COUNT_TIMEOUT: Duration = Duration::from_secs(10);
+const STORE_FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+/// Longest chain of manifests extending each other, guards against cycles
+const MAX_EXTENDS_DEPTH: usize = 5;
+/// Lists merged by 'merge_manifests' and the field identifying their entries
+const MERGED_LISTS: [(&str, &str); 6] = [
+    ("mods", "name"),
+    ("shaderpacks", "name"),
+    ("resourcepacks", "name"),
+    ("features", "id"),
+    ("include", "location"),
+    ("presets", "name"),
+];
+/// Max width and height of icons embedded in 'launcher_profiles.json'
+const MAX_ICON_SIZE: u32 = 256;
+const DATA_DIR_ERROR: &str =
+    "Could not determine the data directory, set 'WC_OVHL_ROOT' to the directory containing your launchers";
+/// Download hosts whose certificates are checked against 'Config::pinned_ca' besides the ones of the 'Endpoints'
+const PINNED_DOWNLOAD_HOSTS: [&str; 3] = [
+    "cdn.modrinth.com",
+    "objects.githubusercontent.com",
+    "release-assets.githubusercontent.com",
+];
+/// Redirects a download may follow unless 'Config::max_redirects' or 'Config::redirect_limits' say otherwise
+const DEFAULT_MAX_REDIRECTS: u32 = 10;
+const INSTALLER_RELEASES: &str = "https://github.com/Wynncraft-Overhaul/installer/releases/latest";
+const BRANCH_FETCH_TIMEOUT: Duration = Duration::from_secs(15);
+/// Waits before retrying to fetch the branches, see 'fetch_branches'
+const BRANCH_FETCH_BACKOFF: [Duration; 2] = [Duration::from_secs(1), Duration::from_secs(3)];
+/// Kept short as the window only opens after the prefetch, see 'prefetch_manifest'
+const PREFETCH_TIMEOUT: Duration = Duration::from_secs(3);
+/// Records the items downloaded by an unfinished install, see 'record_install_progress'
+const INSTALL_PROGRESS: &str = ".install-progress";
+const DEFAULT_WINDOW_TITLE: &str = "Majestic Overhaul Installer";
+const DEFAULT_WINDOW_SIZE: (u32, u32) = (960, 540);
+
+/// Base urls of the APIs the installer talks to, can be pointed at mirrors or a mock server through 'Config::endpoints'
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+#[serde(default)]
+struct Endpoints {
+    github_api: String,
+    github_raw: String,
+    modrinth_api: String,
+    fabric_meta: String,
+    quilt_meta: String,
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Endpoints {
+            github_api: String::from(GITHUB_API),
+            github_raw: String::from("https://raw.githubusercontent.com/"),
+            modrinth_api: String::from("https://api.modrinth.com/v2/"),
+            fabric_meta: String::from("https://meta.fabricmc.net/v2/"),
+            quilt_meta: String::from("https://meta.quiltmc.org/v3/"),
+        }
+    }
+}
+
+impl Endpoints {
+    /// The endpoints from 'config.json', missing ones use the public APIs
+    fn from_config() -> Endpoints {
+        read_config()
+            .and_then(|config| config.endpoints)
+            .unwrap_or_default()
+    }
+
+    /// Hosts whose certificates are checked against 'Config::pinned_ca' if it's set, i.e. the hosts of the endpoints
+    /// and the 'PINNED_DOWNLOAD_HOSTS'
+    fn pinned_hosts(&self) -> Vec<String> {
+        [&self.github_api, &self.github_raw, &self.modrinth_api, &self.fabric_meta, &self.quilt_meta]
+            .into_iter()
+            .filter_map(|x| x.parse::<Uri>().ok()?.host().map(|x| x.to_string()))
+            .chain(PINNED_DOWNLOAD_HOSTS.iter().map(|x| x.to_string()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PackName {
+    name: String,
+    uuid: String,
+    manifest_version: i32,
+    modpack_version: String,
+}
+
+/// What is installed of a modpack, see 'pack_status'
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackStatus {
+    pub installed: bool,
+    /// 'None' if not installed or the local manifest is invalid
+    pub modpack_version: Option<String>,
+    pub enabled_features: Vec<String>,
+    /// 'None' if it wasn't checked
+    pub update_available: Option<bool>,
+}
+
+impl PackStatus {
+    /// 'latest_version' is the 'modpack_version' of the newest manifest, if it's known
+    fn new(
+        local_manifest: Option<&Result<Manifest, String>>,
+        latest_version: Option<&str>,
+    ) -> PackStatus {
+        let valid = local_manifest.and_then(|x| x.as_ref().ok());
+        PackStatus {
+            installed: local_manifest.is_some(),
+            modpack_version: valid.map(|x| x.modpack_version.clone()),
+            enabled_features: valid.map(|x| x.enabled_features.clone()).unwrap_or_default(),
+            update_available: match (local_manifest, latest_version) {
+                (Some(Ok(local)), Some(latest)) => Some(local.modpack_version != latest),
+                // An invalid local manifest can't be updated, only reinstalled
+                (Some(Err(_)), Some(_)) => Some(false),
+                (None, Some(_)) => Some(false),
+                (_, None) => None,
+            },
+        }
+    }
+}
+
+fn default_id() -> String {
+    String::from("default")
+}
+
+fn default_enabled_features() -> Vec<String> {
+    vec![default_id()]
+}
+
+fn default_hidden() -> bool {
+    false
+}
+
+fn default_side() -> String {
+    String::from("both")
+}
+
+/// Side of the game an install is for, items with another 'side' than this or "both" are skipped
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Side {
+    Client,
+    Server,
+}
+
+impl Side {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Side::Client => "client",
+            Side::Server => "server",
+        }
+    }
+}
+
+macro_rules! add_headers {
+    ($items:expr, $($headers:expr),*) => {
+        $items.$(header($headers.next().unwrap().0, $headers.next().unwrap().1))*
+    };
+}
+
+#[derive(Debug)]
+struct CachedResponse {
+    resp: Response<AsyncBody>,
+    bytes: Vec<u8>,
+}
+
+fn resp_rebuilder(resp: &Response<AsyncBody>, bytes: &Vec<u8>) -> Response<AsyncBody> {
+    let builder = Response::builder()
+        .status(resp.status())
+        .version(resp.version());
+    let builder = add_headers!(builder, resp.headers().into_iter());
+    builder.body(AsyncBody::from(bytes.to_owned())).unwrap()
+}
+
+impl CachedResponse {
+    async fn new(mut resp: Response<AsyncBody>) -> Self {
+        let bytes = resp.bytes().await.unwrap();
+
+        Self {
+            resp: resp_rebuilder(&resp, &bytes),
+            bytes,
+        }
+    }
+}
+
+impl Clone for CachedResponse {
+    fn clone(&self) -> Self {
+        Self {
+            resp: resp_rebuilder(&self.resp, &self.bytes),
+            bytes: self.bytes.clone(),
+        }
+    }
+}
+
+/// Maps download urls to the file they were written to
+type DownloadMap = HashMap<String, Arc<futures::lock::Mutex<Option<PathBuf>>>>;
+
+#[derive(Debug, Clone)]
+struct CachedHttpClient {
+    http_client: HttpClient,
+    /// Files downloaded during the current install, see 'download_file'
+    downloads: Arc<Mutex<DownloadMap>>,
+    /// Paths written to during the current install, used to avoid two items overwriting each other
+    claimed_paths: Arc<Mutex<HashSet<PathBuf>>>,
+    /// Shared store for the current install, 'None' if disabled
+    store: Arc<Mutex<Option<PathBuf>>>,
+    endpoints: Endpoints,
+}
+
+impl CachedHttpClient {
+    fn new() -> CachedHttpClient {
+        CachedHttpClient {
+            http_client: build_http_client(),
+            downloads: Arc::new(Mutex::new(HashMap::new())),
+            claimed_paths: Arc::new(Mutex::new(HashSet::new())),
+            store: Arc::new(Mutex::new(None)),
+            endpoints: Endpoints::from_config(),
+        }
+    }
+
+    /// Client for another profile which reuses this one's connection pool, the per-install state starts out empty
+    fn share(&self) -> CachedHttpClient {
+        CachedHttpClient {
+            http_client: self.http_client.clone(),
+            downloads: Arc::new(Mutex::new(HashMap::new())),
+            claimed_paths: Arc::new(Mutex::new(HashSet::new())),
+            store: Arc::new(Mutex::new(None)),
+            endpoints: self.endpoints.clone(),
+        }
+    }
+
+    async fn get_async<T: Into<String> + Clone + Debug>(
+        &self,
+        url: T,
+    ) -> Result<Response<AsyncBody>, RequestError> {
+        let mut err = None;
+        for _ in 0..ATTEMPTS {
+            let resp = get_cached(&self.http_client, url.clone().into()).await;
+            match resp {
+                Ok(v) => return Ok(v.resp),
+                // Retrying won't help until the rate limit resets
+                Err(v @ RequestError::RateLimited(_)) => {
+                    error!("Failed to get '{url:?}', returned '{v:#?}'.");
+                    return Err(v);
+                }
+                Err(v) => err = Some(v),
+            }
+            warn!("Failed to get '{url:?}', returned '{err:#?}'. Retrying!");
+            sleep(WAIT_BETWEEN_ATTEMPTS);
+        }
+        error!("Failed to get '{url:?}', returned '{err:#?}'.");
+        Err(err.unwrap()) // unwrap can't fail
+    }
+
+    async fn get_nocache<T: Into<String> + Clone>(
+        &self,
+        url: T,
+    ) -> Result<Response<AsyncBody>, isahc::Error> {
+        self.with_headers(url, &[]).await
+    }
+
+    async fn with_headers<T: Into<String> + Clone>(
+        &self,
+        url: T,
+        headers: &[(&str, &str)],
+    ) -> Result<Response<AsyncBody>, isahc::Error> {
+        let mut err = None;
+        for _ in 0..ATTEMPTS {
+            let resp = self
+                .http_client
+                .send_async(build_get_request(url.clone(), headers)?)
+                .await;
+            match resp {
+                Ok(v) => return Ok(v),
+                Err(v) => err = Some(v),
+            }
+            sleep(WAIT_BETWEEN_ATTEMPTS);
+        }
+        Err(err.unwrap()) // unwrap can't fail
+    }
+
+    /// Same as 'with_headers' but without verifying the TLS certificate, see 'allows_insecure_tls'.
+    /// Redirects are followed one hop at a time so a hop to a host which doesn't allow it is verified again.
+    async fn insecure<T: Into<String>>(
+        &self,
+        url: T,
+        headers: &[(&str, &str)],
+    ) -> Result<Response<AsyncBody>, isahc::Error> {
+        let mut url: String = url.into();
+        let host = url.parse::<Uri>().ok().and_then(|x| x.host().map(|x| x.to_string()));
+        for _ in 0..=redirect_limit(read_config().as_ref(), host.as_deref()) {
+            if !allows_insecure_tls(&url) {
+                return self.with_headers(url, headers).await;
+            }
+            let request = get_request_builder(url.clone(), headers)
+                .ssl_options(
+                    SslOption::DANGER_ACCEPT_INVALID_CERTS
+                        | SslOption::DANGER_ACCEPT_INVALID_HOSTS
+                        | SslOption::DANGER_ACCEPT_REVOKED_CERTS,
+                )
+                .redirect_policy(RedirectPolicy::None)
+                .body(())?;
+            let resp = self.http_client.send_async(request).await?;
+            let location = resp
+                .headers()
+                .get("location")
+                .and_then(|x| x.to_str().ok())
+                .filter(|_| resp.status().is_redirection())
+                .and_then(|x| resolve_location(&url, x));
+            match location {
+                Some(location) => url = location,
+                None => return Ok(resp),
+            }
+        }
+        Err(isahc::error::ErrorKind::TooManyRedirects.into())
+    }
+
+    async fn head<T: Into<String>>(
+        &self,
+        url: T,
+        headers: &[(&str, &str)],
+    ) -> Result<Response<AsyncBody>, isahc::Error> {
+        let request = get_request_builder(url, headers).method("HEAD").body(())?;
+        self.http_client.send_async(request).await
+    }
+
+    /// Drops the cached response for 'url' so the next 'get_async' fetches it again
+    async fn invalidate<T: Into<String>>(&self, url: T) {
+        GET_CACHEABLE.lock().await.cache_remove(&url.into());
+    }
+}
+
+/// Gets 'url' through the response cache, responses above 'MAX_CACHED_RESPONSE_SIZE' are returned without caching them
+async fn get_cached(http_client: &HttpClient, url: String) -> Result<CachedResponse, RequestError> {
+    match get_cacheable(http_client, url).await {
+        Err(RequestError::TooLargeToCache(resp)) => Ok(*resp),
+        v => v,
+    }
+}
+
+// Only 'Ok' values are cached so that rate limited or failed requests can be retried
+#[cached(
+    ty = "SizedCache<String, CachedResponse>",
+    create = "{ SizedCache::with_size(100) }",
+    convert = r#"{ format!("{}", url) }"#,
+    result = true
+)]
+async fn get_cacheable(http_client: &HttpClient, url: String) -> Result<CachedResponse, RequestError> {
+    let request = match build_get_request(url, &[]) {
+        Ok(v) => v,
+        Err(e) => return Err(RequestError::HttpError(e)),
+    };
+    let resp = http_client.send_async(request).await;
+    match resp {
+        Ok(val) => {
+            check_rate_limit(&val)?;
+            let resp = CachedResponse::new(val).await;
+            // Returned as an error so it isn't cached, 'get_cached' turns it back into a response
+            if resp.bytes.len() > MAX_CACHED_RESPONSE_SIZE {
+                return Err(RequestError::TooLargeToCache(Box::new(resp)));
+            }
+            Ok(resp)
+        }
+        Err(err) => Err(RequestError::HttpError(err)),
+    }
+}
+
+/// Returns 'RequestError::RateLimited' if the response is a GitHub API rate limit response
+fn check_rate_limit<T>(resp: &Response<T>) -> Result<(), RequestError> {
+    if resp.status() != StatusCode::FORBIDDEN && resp.status() != StatusCode::TOO_MANY_REQUESTS {
+        return Ok(());
+    }
+    let header = |name: &str| {
+        resp.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string())
+    };
+    if header("x-ratelimit-remaining").as_deref() != Some("0") {
+        return Ok(());
+    }
+    let reset = header("x-ratelimit-reset").and_then(|v| v.parse::<u64>().ok());
+    Err(RequestError::RateLimited(reset))
+}
+
+/// Builds a GET request, authenticating it when it targets the GitHub API and `GITHUB_TOKEN` is set.
+/// The token is deliberately not added to the client's default headers so it never leaks to download hosts,
+/// for the same reason it isn't sent to a 'github_api' endpoint configured to point somewhere else.
+/// Fails if 'url' isn't a valid url, e.g. an empty item location.
+fn build_get_request<T: Into<String>>(url: T, headers: &[(&str, &str)]) -> Result<Request<()>, isahc::Error> {
+    Ok(get_request_builder(url, headers).body(())?)
+}
+
+/// Same as 'build_get_request' but allows further configuration of the request, e.g. a timeout
+fn get_request_builder<T: Into<String>>(url: T, headers: &[(&str, &str)]) -> isahc::http::request::Builder {
+    let url = url.into();
+    let mut builder = Request::get(&url);
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    if let Ok(token) = env::var("GITHUB_TOKEN") {
+        if url.starts_with(GITHUB_API) {
+            builder = builder.header("Authorization", format!("Bearer {token}"));
+        }
+    }
+    let host = url.parse::<Uri>().ok().and_then(|x| x.host().map(|x| x.to_string()));
+    if let Some(auth) = host.as_deref().and_then(get_host_auth) {
+        if !url.starts_with("https://") {
+            warn!("Not sending the credentials configured for '{}' over an unencrypted connection", host.as_deref().unwrap());
+        } else if !headers.iter().any(|(name, _)| name.eq_ignore_ascii_case(&auth.header)) {
+            // Headers of the item win so a manifest can't be broken by a stale credential for the same header
+            builder = builder.header(auth.header, auth.value);
+        }
+    }
+    let config = read_config();
+    // Mirrors can chain shortlinks, CDNs and signed urls, they need more hops than the client's default allows
+    if let Some(limit) = host.as_deref().and_then(|x| host_redirect_limit(config.as_ref(), x)) {
+        builder = builder.redirect_policy(RedirectPolicy::Limit(limit));
+    }
+    if host.is_some_and(|x| Endpoints::from_config().pinned_hosts().contains(&x)) {
+        if let Some(pinned_ca) = config.and_then(|config| config.pinned_ca) {
+            // Replaces the system store so a certificate from any other CA fails the handshake
+            builder = builder.ssl_ca_certificate(CaCertificate::file(pinned_ca));
+        }
+    }
+    builder
+}
+
+/// Returns the limit set for 'host' in 'Config::redirect_limits', hosts are matched exactly (ignoring case)
+fn host_redirect_limit(config: Option<&Config>, host: &str) -> Option<u32> {
+    config?
+        .redirect_limits
+        .as_ref()?
+        .iter()
+        .find(|(x, _)| x.eq_ignore_ascii_case(host))
+        .map(|(_, limit)| *limit)
+}
+
+/// Returns how many redirects a download from 'host' may follow, 'None' returns the limit of every other host
+fn redirect_limit(config: Option<&Config>, host: Option<&str>) -> u32 {
+    host.and_then(|x| host_redirect_limit(config, x))
+        .or_else(|| config.and_then(|x| x.max_redirects))
+        .unwrap_or(DEFAULT_MAX_REDIRECTS)
+}
+
+/// Credential sent to a host which requires authentication, see 'Config::host_auth'
+#[derive(Deserialize, Serialize, PartialEq, Clone)]
+struct HostAuth {
+    #[serde(default = "default_auth_header")]
+    header: String,
+    value: String,
+}
+
+// The config is logged on startup, the credential must not end up in 'installer.log'
+impl Debug for HostAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HostAuth")
+            .field("header", &self.header)
+            .field("value", &"<redacted>")
+            .finish()
+    }
+}
+
+fn default_auth_header() -> String {
+    String::from("Authorization")
+}
+
+/// Returns the credential configured for 'host' in 'config.json', hosts are matched exactly (ignoring case)
+fn get_host_auth(host: &str) -> Option<HostAuth> {
+    read_config()
+        .and_then(|config| config.host_auth)
+        .and_then(|host_auth| {
+            host_auth
+                .into_iter()
+                .find(|(x, _)| x.eq_ignore_ascii_case(host))
+                .map(|(_, auth)| auth)
+        })
+}
+
+/// Hosts whose certificates are always verified, even if 'insecure_ddl' is enabled
+const VERIFIED_HOST_SUFFIXES: [&str; 3] = ["github.com", "githubusercontent.com", "modrinth.com"];
+
+/// Resolves the 'Location' header of a redirect from 'url', 'None' if 'url' isn't a valid url
+fn resolve_location(url: &str, location: &str) -> Option<String> {
+    if location.starts_with("http://") || location.starts_with("https://") {
+        return Some(location.to_string());
+    }
+    let uri = url.parse::<Uri>().ok()?;
+    let scheme = uri.scheme_str()?;
+    let origin = format!("{scheme}://{}", uri.authority()?);
+    Some(if let Some(location) = location.strip_prefix("//") {
+        format!("{scheme}://{location}")
+    } else if location.starts_with('/') {
+        origin + location
+    } else {
+        resolve_extends_location(&(origin + uri.path()), location)
+    })
+}
+
+/// Whether the TLS certificate of 'url' may be ignored. Only 'ddl' downloads ask for this and only if the user
+/// enabled 'insecure_ddl' in the settings, which is meant for mirrors with broken certificates.
+/// The hosts of the configured 'Endpoints' are always verified, just like the public ones.
+fn allows_insecure_tls(url: &str) -> bool {
+    if !read_config()
+        .and_then(|config| config.insecure_ddl)
+        .unwrap_or(false)
+    {
+        return false;
+    }
+    match url.parse::<Uri>().ok().and_then(|x| x.host().map(|x| x.to_string())) {
+        Some(host) => {
+            !Endpoints::from_config().pinned_hosts().contains(&host)
+                && !VERIFIED_HOST_SUFFIXES.iter().any(|x| host.ends_with(x))
+        }
+        None => false,
+    }
+}
+
+/// Fetches the modpack branches, each branch is a version of the modpack.
+/// Retried with 'BRANCH_FETCH_BACKOFF' so a transient failure doesn't leave the GUI empty.
+async fn fetch_branches(modpack_source: String) -> Result<Vec<GithubBranch>, InstallError> {
+    let mut res = fetch_branches_with_timeout(&modpack_source, BRANCH_FETCH_TIMEOUT, false).await;
+    for wait in BRANCH_FETCH_BACKOFF {
+        if res.is_ok() {
+            break;
+        }
+        warn!("Retrying to fetch branches in {wait:?}");
+        // Not tied to a runtime as 'manifest-lint' drives this with 'block_on'
+        async_io::Timer::after(wait).await;
+        // A proxy could otherwise keep serving the failed response
+        res = fetch_branches_with_timeout(&modpack_source, BRANCH_FETCH_TIMEOUT, true).await;
+    }
+    res
+}
+
+async fn fetch_branches_with_timeout(
+    modpack_source: &str,
+    timeout: Duration,
+    bypass_cache: bool,
+) -> Result<Vec<GithubBranch>, InstallError> {
+    let url = Endpoints::from_config().github_api + modpack_source + "branches";
+    let headers: &[(&str, &str)] = if bypass_cache {
+        &[("Cache-Control", "no-cache"), ("Pragma", "no-cache")]
+    } else {
+        &[]
+    };
+    // Only fails for a 'modpack_source' which doesn't form a valid URL
+    let request = match get_request_builder(url, headers).timeout(timeout).body(()) {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Other(format!("Invalid modpack source '{modpack_source}': {e}"))),
+    };
+    let mut resp = match build_http_client().send_async(request).await {
+        Ok(v) => v,
+        Err(e) => {
+            error!("Failed to retrieve branches: {e}");
+            return Err(InstallError::Network(e.to_string()));
+        }
+    };
+    if let Err(e) = check_rate_limit(&resp) {
+        error!("Failed to retrieve branches: {e}");
+        return Err(e.into());
+    }
+    let text = match resp.text().await {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Network(e.to_string())),
+    };
+    match serde_json::from_str(&text) {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            error!("Failed to parse branches: {e}\n{text}");
+            Err(InstallError::Parse(format!("Failed to parse branches: {e}")))
+        }
+    }
+}
+
+/// Whether 'modpack_source' is the installer's own modpack or listed in 'Config::trusted_sources'
+fn is_trusted_source(modpack_source: &str) -> bool {
+    modpack_source.eq_ignore_ascii_case(REPO)
+        || read_config()
+            .and_then(|config| config.trusted_sources)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|x| parse_modpack_source(x).ok())
+            .any(|x| x.eq_ignore_ascii_case(modpack_source))
+}
+
+/// Normalizes a GitHub 'owner/repo' or repository URL entered by the user to a 'modpack_source'
+/// ('owner/repo/'), an empty input selects the default modpack
+pub fn parse_modpack_source(input: &str) -> Result<String, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(String::from(REPO));
+    }
+    let path = input
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("www.")
+        .trim_start_matches("github.com/")
+        .trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let parts: Vec<&str> = path.split('/').collect();
+    let valid_part = |x: &str| {
+        !x.is_empty()
+            && x != "."
+            && x != ".."
+            && x.chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+    if parts.len() == 2 && parts.iter().all(|x| valid_part(x)) {
+        Ok(format!("{}/{}/", parts[0], parts[1]))
+    } else {
+        Err(format!(
+            "'{input}' is not a GitHub repository, expected 'owner/repo' or its URL"
+        ))
+    }
+}
+
+/// Returns whether 'branch' is a release according to the configured release prefixes, without any every branch is one
+fn is_release_branch(config: &Config, branch: &str) -> bool {
+    match &config.release_prefixes {
+        Some(prefixes) => prefixes.iter().any(|x| branch.starts_with(x.as_str())),
+        None => true,
+    }
+}
+
+/// Path and modification time of 'config.json' when it was read, used to notice when it changed
+type CachedConfig = (PathBuf, Option<SystemTime>, Option<Config>);
+
+/// 'config.json' as last read, see 'read_config'
+static CONFIG: Mutex<Option<CachedConfig>> = Mutex::new(None);
+
+/// Returns 'config.json', 'None' if it doesn't exist (yet) or is invalid.
+/// The file is only read again once it was modified, e.g. by the settings or by hand.
+fn read_config() -> Option<Config> {
+    let path = get_config_path().ok()?.join("config.json");
+    let modified = fs::metadata(&path).and_then(|x| x.modified()).ok();
+    let mut cached = CONFIG.lock().unwrap();
+    match &*cached {
+        Some((cached_path, cached_modified, config)) if *cached_path == path && *cached_modified == modified => {
+            config.clone()
+        }
+        _ => {
+            let config = fs::read(&path)
+                .ok()
+                .and_then(|contents| serde_json::from_slice::<Config>(&contents).ok());
+            *cached = Some((path, modified, config.clone()));
+            config
+        }
+    }
+}
+
+/// Writes 'config' to 'config_path', it applies to everything fetched afterwards
+fn save_config(config_path: &Path, config: &Config) -> Result<(), std::io::Error> {
+    fs::write(config_path, serde_json::to_vec(config).unwrap())?;
+    // Saving twice within the resolution of the modification time mustn't keep the old config
+    *CONFIG.lock().unwrap() = None;
+    Ok(())
+}
+
+/// Returns the proxy from 'config.json' falling back to the standard proxy env vars.
+/// Changes saved from the settings apply to clients built afterwards, see 'save_config'.
+fn get_proxy() -> Option<String> {
+    let config_proxy = read_config().and_then(|config| config.proxy);
+    config_proxy
+        .or_else(|| {
+            ["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"]
+                .iter()
+                .find_map(|var| env::var(var).ok())
+        })
+        .filter(|v| !v.is_empty())
+}
+
+/// Returns whether the shared store is enabled in 'config.json'
+fn use_shared_store() -> bool {
+    read_config()
+        .and_then(|config| config.shared_store)
+        .unwrap_or(false)
+}
+
+/// Returns the physical RAM in mb, 'None' if it couldn't be detected
+fn get_system_ram() -> Option<u64> {
+    let system = System::new_with_specifics(
+        RefreshKind::new().with_memory(MemoryRefreshKind::new().with_ram()),
+    );
+    Some(system.total_memory() / 1024 / 1024).filter(|v| *v != 0)
+}
+
+/// Returns half of the physical RAM in mb, clamped to a sensible range. 'None' if it couldn't be detected.
+fn get_system_max_mem() -> Option<i32> {
+    get_system_ram().map(|total| (total / 2).clamp(MIN_DEFAULT_MAX_MEM, MAX_DEFAULT_MAX_MEM) as i32)
+}
+
+/// Keeps 'max_mem' below 'MAX_MEM_RAM_PERCENT' of 'total_ram' (mb) and 'min_mem' at most 'max_mem'.
+/// Returns the memory values to use and a message for every value that was changed.
+fn clamp_memory(
+    max_mem: Option<i32>,
+    min_mem: Option<i32>,
+    total_ram: Option<u64>,
+) -> (Option<i32>, Option<i32>, Vec<String>) {
+    let mut adjustments = vec![];
+    let (mut max_mem, mut min_mem) = (max_mem, min_mem);
+    if let Some(total_ram) = total_ram {
+        let ceiling = (total_ram * MAX_MEM_RAM_PERCENT / 100).max(MIN_DEFAULT_MAX_MEM) as i32;
+        if let Some(max) = max_mem.filter(|v| *v > ceiling) {
+            adjustments.push(if max as u64 > total_ram {
+                format!("Max memory of {max} MB is more than the {total_ram} MB of RAM this computer has, using {ceiling} MB instead")
+            } else {
+                format!("Max memory of {max} MB leaves too little RAM for the rest of the system, using {ceiling} MB instead")
+            });
+            max_mem = Some(ceiling);
+        }
+        if let Some(min) = min_mem.filter(|v| max_mem.is_none() && *v > ceiling) {
+            adjustments.push(format!(
+                "Min memory of {min} MB leaves too little RAM for the rest of the system, using {ceiling} MB instead"
+            ));
+            min_mem = Some(ceiling);
+        }
+    }
+    if let (Some(min), Some(max)) = (min_mem, max_mem) {
+        if min > max {
+            adjustments.push(format!(
+                "Min memory of {min} MB is more than the max memory of {max} MB, using {max} MB instead"
+            ));
+            min_mem = Some(max);
+        }
+    }
+    (max_mem, min_mem, adjustments)
+}
+
+/// Returns the max memory for modpacks which don't set 'max_mem', the value from 'config.json' wins over the computed one
+fn get_default_max_mem() -> Option<i32> {
+    read_config()
+        .and_then(|config| config.max_mem)
+        .or_else(get_system_max_mem)
+}
+
+fn get_store_root(launcher: &Launcher) -> PathBuf {
+    match launcher {
+        Launcher::Vanilla(root) => root.join(".WC_OVHL/store"),
+        Launcher::MultiMC(root) => root.join(".WC_OVHL/store"),
+        Launcher::Modrinth(root) => root.join(".WC_OVHL/store"),
+    }
+}
+
+fn build_http_client() -> HttpClient {
+    let proxy = match get_proxy() {
+        // 'NO_PROXY' is still respected by curl when a proxy is set
+        Some(v) => match v.parse::<Uri>() {
+            Ok(uri) => Some(uri),
+            Err(e) => {
+                warn!("Ignoring invalid proxy '{v}': {e}");
+                None
+            }
+        },
+        None => None,
+    };
+    HttpClient::builder()
+        .redirect_policy(RedirectPolicy::Limit(redirect_limit(read_config().as_ref(), None)))
+        .proxy(proxy)
+        .default_headers(&[(
+            "User-Agent",
+            concat!("wynncraft-overhaul/installer/", env!("CARGO_PKG_VERSION")),
+        )])
+        .build()
+        .unwrap()
+}
+
+/// Filesystem operations of the logic deciding what to keep, so it can be tested without touching the disk.
+/// Used to remove old items and includes, enable or disable items, read 'overrides.json' and save the local manifest
+/// when only features change. Downloading and extracting write to the disk directly.
+trait FileSystem: Debug + Send + Sync {
+    fn read(&self, path: &Path) -> Result<Vec<u8>, std::io::Error>;
+    fn write(&self, path: &Path, contents: &[u8]) -> Result<(), std::io::Error>;
+    fn remove_file(&self, path: &Path) -> Result<(), std::io::Error>;
+    fn rename(&self, from: &Path, to: &Path) -> Result<(), std::io::Error>;
+}
+
+#[derive(Debug)]
+struct RealFileSystem;
+
+impl FileSystem for RealFileSystem {
+    fn read(&self, path: &Path) -> Result<Vec<u8>, std::io::Error> {
+        fs::read(path)
+    }
+
+    fn write(&self, path: &Path, contents: &[u8]) -> Result<(), std::io::Error> {
+        write_atomic(path, contents)
+    }
+
+    fn remove_file(&self, path: &Path) -> Result<(), std::io::Error> {
+        fs::remove_file(path)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<(), std::io::Error> {
+        fs::rename(from, to)
+    }
+}
+
+#[async_trait]
+trait Downloadable {
+    async fn download(
+        &self,
+        modpack_root: &Path,
+        loader_type: &str,
+        minecraft_version: &str,
+        http_client: &CachedHttpClient,
+    ) -> Result<PathBuf, DownloadError>;
+
+    /// Resolves the item like 'download' does and checks that its file is reachable without downloading it
+    async fn probe(
+        &self,
+        loader_type: &str,
+        minecraft_version: &str,
+        http_client: &CachedHttpClient,
+    ) -> Result<(), DownloadError>;
+
+    /// Returns a copy of the item with 'path' replaced
+    fn with_path(&self, path: Option<PathBuf>) -> Self;
+    fn get_name(&self) -> &String;
+    fn get_location(&self) -> &String;
+    fn get_version(&self) -> &String;
+    fn get_path(&self) -> &Option<PathBuf>;
+    fn get_id(&self) -> &String;
+    fn get_source(&self) -> &String;
+    fn get_side(&self) -> &String;
+    fn get_headers(&self) -> &Option<Vec<(String, String)>>;
+    fn get_subfolder(&self) -> &Option<String>;
+    fn get_priority(&self) -> &Option<i32>;
+    fn get_authors(&self) -> &Vec<Author>;
+    fn with_authors(&self, authors: Vec<Author>) -> Self;
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+struct Config {
+    launcher: String,
+    first_launch: Option<bool>, // option for backwars compatibiliy
+    proxy: Option<String>,
+    /// Store downloaded items once in '.WC_OVHL/store' and hard link them into each modpack
+    shared_store: Option<bool>,
+    /// Locale code of the GUI language, 'None' uses the OS locale
+    locale: Option<String>,
+    /// Show branches which aren't releases, see 'is_release_branch'
+    show_beta: Option<bool>,
+    /// Branches starting with one of these are releases, everything else is only shown with 'show_beta'.
+    /// 'None' treats every branch as a release.
+    release_prefixes: Option<Vec<String>>,
+    /// PEM bundle of the only CA certificates accepted for 'Endpoints::pinned_hosts', unset to use the system store
+    pinned_ca: Option<String>,
+    /// Max memory (mb) for modpacks which don't set 'max_mem', 'None' computes it from the system RAM
+    max_mem: Option<i32>,
+    /// Overrides the API base urls, e.g. to use a mirror
+    endpoints: Option<Endpoints>,
+    /// Always apply the modpack's memory and java args, even over values the user changed in their launcher
+    force_manifest_jvm: Option<bool>,
+    /// Unsafe, don't verify TLS certificates when downloading 'ddl' items. Never applies to GitHub or Modrinth.
+    insecure_ddl: Option<bool>,
+    /// Opt-in, lets modpacks with an 'install_count_endpoint' count their installs, see 'ping_install_count'
+    install_counting: Option<bool>,
+    /// List branches in a sidebar and only show the selected one instead of a card per branch
+    compact_view: Option<bool>,
+    /// Keep saves, screenshots and logs next to the modpack root so reinstalls don't remove them, see 'link_user_data'
+    separate_user_data: Option<bool>,
+    /// Auth header per host for downloads from gated hosts, e.g. private CDNs.
+    /// Only read from the user's config, manifests can't set or see credentials.
+    host_auth: Option<HashMap<String, HostAuth>>,
+    /// Modpack sources ('owner/repo/') besides 'REPO' which may render unsanitized HTML, see 'Manifest::raw_html'
+    trusted_sources: Option<Vec<String>>,
+    /// Redirects every download may follow, 'None' uses 'DEFAULT_MAX_REDIRECTS'
+    max_redirects: Option<u32>,
+    /// Overrides 'max_redirects' per host, e.g. for mirrors which redirect more often
+    redirect_limits: Option<HashMap<String, u32>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+struct Author {
+    name: String,
+    link: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+struct Included {
+    md5: String,
+    files: Vec<String>,
+    /// Size, modification time and hash of every file in 'files', missing for includes installed by older versions
+    #[serde(default)]
+    checksums: HashMap<String, IncludedFile>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+struct IncludedFile {
+    size: u64,
+    /// Milliseconds since the unix epoch
+    mtime: u64,
+    sha256: String,
+}
+
+impl IncludedFile {
+    fn stat(path: &Path) -> Option<(u64, u64)> {
+        let metadata = fs::metadata(path).ok()?;
+        let mtime = metadata
+            .modified()
+            .ok()?
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .ok()?
+            .as_millis() as u64;
+        Some((metadata.len(), mtime))
+    }
+
+    fn new(path: &Path) -> Option<IncludedFile> {
+        let (size, mtime) = IncludedFile::stat(path)?;
+        Some(IncludedFile {
+            size,
+            mtime,
+            sha256: sha256_hex(&fs::read(path).ok()?),
+        })
+    }
+}
+
+impl Included {
+    fn new(md5: String, files: Vec<String>) -> Included {
+        let checksums = files
+            .iter()
+            .filter_map(|file| Some((file.clone(), IncludedFile::new(Path::new(file))?)))
+            .collect();
+        Included { md5, files, checksums }
+    }
+
+    /// Checks that the extracted files are still the ones that were installed.
+    /// Files are only re-hashed if their size or modification time changed, returns the refreshed entry or 'None' if a file was modified or removed.
+    fn verify(&self) -> Option<Included> {
+        let mut checksums = HashMap::new();
+        for file in &self.files {
+            let path = Path::new(file);
+            let (size, mtime) = IncludedFile::stat(path)?;
+            match self.checksums.get(file) {
+                Some(checksum) if checksum.size == size && checksum.mtime == mtime => {
+                    checksums.insert(file.clone(), checksum.clone());
+                }
+                Some(checksum) => {
+                    let current = IncludedFile::new(path)?;
+                    if current.sha256 != checksum.sha256 {
+                        info!("'{file}' was modified");
+                        return None;
+                    }
+                    checksums.insert(file.clone(), current);
+                }
+                // Installed before checksums were stored, so trust it and record the current state
+                None => {
+                    checksums.insert(file.clone(), IncludedFile::new(path)?);
+                }
+            }
+        }
+        Some(Included {
+            checksums,
+            ..self.clone()
+        })
+    }
+}
+
+macro_rules! gen_downloadble_impl {
+    ($item:ty, $type:literal) => {
+        #[async_trait]
+        impl Downloadable for $item {
+            async fn download(
+                &self,
+                modpack_root: &Path,
+                loader_type: &str,
+                minecraft_version: &str,
+                http_client: &CachedHttpClient,
+            ) -> Result<PathBuf, DownloadError> {
+                info!("Downloading: {self:#?}");
+                let res = match self.source.as_str() {
+                    "modrinth" => {
+                        download_from_modrinth(
+                            self,
+                            modpack_root,
+                            loader_type,
+                            minecraft_version,
+                            $type,
+                            http_client,
+                        )
+                        .await
+                    }
+                    "ddl" => download_from_ddl(self, modpack_root, $type, http_client).await,
+                    "mediafire" => {
+                        download_from_mediafire(self, modpack_root, $type, http_client).await
+                    }
+                    _ => Err(DownloadError::UnsupportedSource(self.name.clone(), self.source.clone())),
+                };
+                info!("Downloaded '{}' with result: {:#?}", self.get_name(), res);
+                res
+            }
+
+            async fn probe(
+                &self,
+                loader_type: &str,
+                minecraft_version: &str,
+                http_client: &CachedHttpClient,
+            ) -> Result<(), DownloadError> {
+                let url = match self.source.as_str() {
+                    "modrinth" => {
+                        resolve_modrinth_file(self, loader_type, minecraft_version, $type, http_client)
+                            .await?
+                            .url
+                    }
+                    "ddl" => self.location.clone(),
+                    "mediafire" => get_mediafire_ddl(self, http_client).await?,
+                    // Also reported by 'validate_manifest', 'lint_manifest' probes the items anyway
+                    _ => return Err(DownloadError::UnsupportedSource(self.name.clone(), self.source.clone())),
+                };
+                let headers: Vec<(&str, &str)> = match &self.headers {
+                    Some(headers) => headers
+                        .iter()
+                        .map(|(name, value)| (name.as_str(), value.as_str()))
+                        .collect(),
+                    None => vec![],
+                };
+                probe_url(self.get_name(), &url, &headers, http_client).await
+            }
+
+            fn with_path(&self, path: Option<PathBuf>) -> Self {
+                Self {
+                    path,
+                    ..self.clone()
+                }
+            }
+
+            fn get_name(&self) -> &String {
+                &self.name
+            }
+            fn get_location(&self) -> &String {
+                &self.location
+            }
+            fn get_version(&self) -> &String {
+                &self.version
+            }
+            fn get_path(&self) -> &Option<PathBuf> {
+                &self.path
+            }
+            fn get_id(&self) -> &String {
+                &self.id
+            }
+            fn get_source(&self) -> &String {
+                &self.source
+            }
+            fn get_side(&self) -> &String {
+                &self.side
+            }
+            fn get_headers(&self) -> &Option<Vec<(String, String)>> {
+                &self.headers
+            }
+            fn get_subfolder(&self) -> &Option<String> {
+                &self.subfolder
+            }
+            fn get_priority(&self) -> &Option<i32> {
+                &self.priority
+            }
+            fn get_authors(&self) -> &Vec<Author> {
+                &self.authors
+            }
+            fn with_authors(&self, authors: Vec<Author>) -> Self {
+                Self {
+                    authors,
+                    ..self.clone()
+                }
+            }
+        }
+    };
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+struct Mod {
+    name: String,
+    source: String,
+    location: String,
+    version: String,
+    path: Option<PathBuf>,
+    #[serde(default = "default_id")]
+    id: String,
+    authors: Vec<Author>,
+    #[serde(default = "default_side")]
+    side: String,
+    headers: Option<Vec<(String, String)>>,
+    /// Directory inside of the item type directory to download into, e.g. 'mods/<subfolder>/'
+    subfolder: Option<String>,
+    /// Load order hint, the filename is prefixed with it zero padded, e.g. '00_' so it loads before other mods
+    priority: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+struct Shaderpack {
+    name: String,
+    source: String,
+    location: String,
+    version: String,
+    path: Option<PathBuf>,
+    #[serde(default = "default_id")]
+    id: String,
+    authors: Vec<Author>,
+    #[serde(default = "default_side")]
+    side: String,
+    headers: Option<Vec<(String, String)>>,
+    subfolder: Option<String>,
+    priority: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+struct Resourcepack {
+    name: String,
+    source: String,
+    location: String,
+    version: String,
+    path: Option<PathBuf>,
+    #[serde(default = "default_id")]
+    id: String,
+    authors: Vec<Author>,
+    #[serde(default = "default_side")]
+    side: String,
+    headers: Option<Vec<(String, String)>>,
+    subfolder: Option<String>,
+    priority: Option<i32>,
+}
+
+gen_downloadble_impl!(Mod, "mod");
+gen_downloadble_impl!(Shaderpack, "shaderpack");
+gen_downloadble_impl!(Resourcepack, "resourcepack");
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+struct Loader {
+    r#type: String,
+    version: String,
+    minecraft_version: String,
+}
+
+/// Result of 'download_loader_json'
+#[derive(Debug, PartialEq)]
+enum LoaderStatus {
+    /// The loader json already existed and matches upstream (if checked)
+    Present,
+    /// The loader json was written to the contained version folder
+    Downloaded(PathBuf),
+}
+
+impl Loader {
+    /// With 'check_upstream' an existing loader json is checked against upstream instead of being reused,
+    /// with 'force' it's written again even if it didn't change, see 'download_loader_json'
+    async fn download(
+        &self,
+        root: &Path,
+        _: &str,
+        http_client: &CachedHttpClient,
+        check_upstream: bool,
+        force: bool,
+    ) -> Result<LoaderStatus, DownloadError> {
+        match self.r#type.as_str() {
+            "fabric" => {
+                download_loader_json(
+                    &format!(
+                        "{}versions/loader/{}/{}/profile/json",
+                        http_client.endpoints.fabric_meta, self.minecraft_version, self.version
+                    ),
+                    &format!("fabric-loader-{}-{}", self.version, self.minecraft_version),
+                    root,
+                    http_client,
+                    check_upstream,
+                    force,
+                )
+                .await
+            }
+            "quilt" => {
+                download_loader_json(
+                    &format!(
+                        "{}versions/loader/{}/{}/profile/json",
+                        http_client.endpoints.quilt_meta, self.minecraft_version, self.version
+                    ),
+                    &format!("quilt-loader-{}-{}", self.version, self.minecraft_version),
+                    root,
+                    http_client,
+                    check_upstream,
+                    force,
+                )
+                .await
+            }
+            _ => panic!("Unsupported loader '{}'!", self.r#type.as_str()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LoaderMetaVersion {
+    version: String,
+    /// Only returned by the Fabric meta, Quilt marks unstable versions with a '-beta' like suffix instead
+    stable: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoaderMeta {
+    loader: LoaderMetaVersion,
+}
+
+/// Resolves a "latest" loader version of the manifest to a concrete one.
+/// The version from the local manifest is kept if there is one so updates and reinstalls stay on the same loader.
+async fn resolve_loader(installer_profile: &InstallerProfile) -> Result<Loader, InstallError> {
+    let loader = &installer_profile.manifest.loader;
+    if let Some(local) = &installer_profile.local_manifest {
+        if local.loader.r#type == loader.r#type
+            && local.loader.minecraft_version == loader.minecraft_version
+            && local.loader.version != LATEST_LOADER
+        {
+            return Ok(local.loader.clone());
+        }
+    }
+    let endpoints = &installer_profile.http_client.endpoints;
+    let url = match loader.r#type.as_str() {
+        "fabric" => format!(
+            "{}versions/loader/{}",
+            endpoints.fabric_meta, loader.minecraft_version
+        ),
+        "quilt" => format!(
+            "{}versions/loader/{}",
+            endpoints.quilt_meta, loader.minecraft_version
+        ),
+        _ => {
+            return Err(InstallError::InvalidManifest(format!(
+                "Unsupported loader '{}'!",
+                loader.r#type
+            )))
+        }
+    };
+    let mut resp = match installer_profile.http_client.get_async(url.clone()).await {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Network(format!("Failed to retrieve loader versions: {e}"))),
+    };
+    if resp.status() != StatusCode::OK {
+        // Don't keep serving the error response from the cache
+        installer_profile.http_client.invalidate(url).await;
+        return Err(InstallError::Network(format!(
+            "Encountered '{}' error code when retrieving loader versions",
+            resp.status().as_u16()
+        )));
+    }
+    let text = match resp.text().await {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Network(format!("Failed to retrieve loader versions: {e}"))),
+    };
+    let versions: Vec<LoaderMeta> = match serde_json::from_str(&text) {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Parse(format!("Failed to parse loader versions: {e}"))),
+    };
+    // The meta lists the newest version first
+    match versions.into_iter().find(|x| {
+        x.loader
+            .stable
+            .unwrap_or_else(|| !x.loader.version.contains('-'))
+    }) {
+        Some(v) => {
+            info!("Resolved latest {} loader to '{}'", loader.r#type, v.loader.version);
+            Ok(Loader {
+                version: v.loader.version,
+                ..loader.clone()
+            })
+        }
+        None => Err(InstallError::InvalidManifest(format!(
+            "No stable {} loader found for Minecraft '{}'",
+            loader.r#type, loader.minecraft_version
+        ))),
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+struct Feature {
+    id: String,
+    name: String,
+    default: bool,
+    #[serde(default = "default_hidden")]
+    hidden: bool,
+    description: Option<String>,
+    /// Operating systems ('windows', 'macos' or 'linux') the feature is meant for, 'None' means all
+    requires_os: Option<Vec<String>>,
+    /// Header the feature is listed under, 'None' puts it in the default group
+    category: Option<String>,
+}
+
+impl Feature {
+    /// Whether the feature applies to the running OS, this is only advisory and users can still enable it
+    fn supports_current_os(&self) -> bool {
+        self.requires_os
+            .as_ref()
+            .is_none_or(|os| os.iter().any(|x| x == env::consts::OS))
+    }
+}
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+struct Server {
+    name: String,
+    address: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+struct Preset {
+    name: String,
+    features: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+struct Include {
+    location: String,
+    #[serde(default = "default_id")]
+    id: String,
+    /// Enables 'id' by default without it having to be a declared feature
+    #[serde(default)]
+    default: bool,
+    name: Option<String>,
+    authors: Option<Vec<Author>>,
+    /// Glob patterns of files the include owns, removed with it even if they aren't in 'Included::files'
+    managed_files: Option<Vec<String>>,
+    /// Direct download of the include zip, used instead of the '<id>.zip' asset of the GitHub release
+    url: Option<String>,
+    /// Sha256 of the zip at 'url', required with it and used as the include's version
+    hash: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+struct RemoteInclude {
+    location: String,
+    path: Option<String>,
+    #[serde(default = "default_id")]
+    id: String,
+    version: String,
+    name: Option<String>,
+    authors: Option<Vec<Author>>,
+    /// Same as 'Include::managed_files'
+    managed_files: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Manifest {
+    manifest_version: i32,
+    modpack_version: String,
+    name: String,
+    subtitle: String,
+    tab_group: Option<usize>,
+    tab_title: Option<String>,
+    tab_color: Option<String>,
+    tab_background: Option<String>,
+    tab_primary_font: Option<String>,
+    tab_secondary_font: Option<String>,
+    settings_background: Option<String>,
+    popup_title: Option<String>,
+    popup_contents: Option<String>,
+    /// Url linked on the screen shown after installing
+    changelog: Option<String>,
+    /// Initial size of the installer window if this is the primary modpack
+    window_width: Option<u32>,
+    window_height: Option<u32>,
+    description: String,
+    /// Render 'description' and 'popup_contents' without sanitizing them, only honored for trusted sources (see 'is_trusted_source')
+    #[serde(default)]
+    raw_html: bool,
+    icon: bool,
+    /// Path of the icon relative to the modpack root, any format supported by 'image' works
+    icon_file: Option<String>,
+    uuid: String,
+    loader: Loader,
+    mods: Vec<Mod>,
+    shaderpacks: Vec<Shaderpack>,
+    resourcepacks: Vec<Resourcepack>,
+    remote_include: Option<Vec<RemoteInclude>>,
+    include: Vec<Include>,
+    features: Vec<Feature>,
+    presets: Option<Vec<Preset>>,
+    #[serde(default = "default_enabled_features")]
+    enabled_features: Vec<String>,
+    included_files: Option<HashMap<String, Included>>,
+    source: Option<String>,
+    installer_path: Option<String>,
+    max_mem: Option<i32>,
+    min_mem: Option<i32>,
+    java_args: Option<String>,
+    /// Command MultiMC runs before launching the instance, the vanilla launcher has no equivalent
+    prelaunch_command: Option<String>,
+    /// Selects the installed shaderpack in Iris's config if exactly one is enabled, see 'activate_shaderpack'
+    activate_shaderpack: Option<bool>,
+    /// Added to the multiplayer server list, see 'add_default_server'
+    default_server: Option<Server>,
+    /// Shell command offered to the user after installing, only run once they approved it, see 'run_post_install_command'
+    post_install_command: Option<String>,
+    /// URL of a base manifest this one is merged into, see 'merge_manifests'
+    extends: Option<String>,
+    /// Receives the uuid and version after an install if the user opted in, see 'ping_install_count'
+    install_count_endpoint: Option<String>,
+    /// Oldest installer version able to install the modpack, e.g. '1.5.0'
+    min_installer_version: Option<String>,
+}
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Serialize)]
+struct LauncherProfile {
+    lastUsed: String,
+    lastVersionId: String,
+    created: String,
+    name: String,
+    icon: Option<String>,
+    r#type: String,
+    gameDir: Option<String>,
+    javaDir: Option<String>,
+    javaArgs: Option<String>,
+    logConfig: Option<String>,
+    logConfigIsXML: Option<bool>,
+    resolution: Option<HashMap<String, i32>>,
+}
+#[derive(Debug, Deserialize, Serialize, Clone)]
+struct ModrinthFile {
+    url: String,
+    filename: String,
+}
+#[derive(Debug, Deserialize, Serialize)]
+struct ModrinthObject {
+    version_number: String,
+    files: Vec<ModrinthFile>,
+    loaders: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ModrinthMember {
+    user: ModrinthUser,
+}
+#[derive(Debug, Deserialize)]
+struct ModrinthUser {
+    username: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct GithubRepo {
+    // Theres a lot more fields but we only care about default_branch
+    // https://docs.github.com/en/rest/repos/repos?apiVersion=2022-11-28#get-a-repository
+    default_branch: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+struct GithubAsset {
+    name: String,
+    id: i32,
+    browser_download_url: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+struct GithubRelease {
+    tag_name: String,
+    body: Option<String>,
+    assets: Vec<GithubAsset>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+struct GithubBranch {
+    name: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Serialize)]
+struct MMCComponent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cachedVolatile: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dependencyOnly: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    important: Option<bool>,
+    uid: String,
+    version: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize, Serialize)]
+struct MMCPack {
+    components: Vec<MMCComponent>,
+    formatVersion: i32,
+}
+
+#[derive(Debug)]
+enum DownloadError {
+    Non200StatusCode(String, u16),
+    FailedToParseResponse(String, serde_json::Error),
+    IoError(String, std::io::Error),
+    HttpError(String, isahc::Error),
+    MissingFilename(String),
+    CouldNotFindItem(String),
+    MedafireMissingDDL(String),
+    /// Mediafire kept serving its captcha page instead of the download page
+    MediafireRateLimited(String),
+    /// The Modrinth project or all of its versions were removed
+    NoLongerAvailable(String),
+    /// The downloaded file doesn't match the sha256 hash from the manifest
+    HashMismatch(String),
+    /// Contains the item and its 'source'
+    UnsupportedSource(String, String),
+    /// The installed path of the item is outside of the modpack root, e.g. in an edited local manifest
+    InvalidPath(String),
+    Cancelled,
+}
+
+impl Display for DownloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DownloadError::Non200StatusCode(item, x) => write!(
+                f,
+                "Encountered '{x}' error code when attempting to download: '{item}'"
+            ),
+
+            DownloadError::FailedToParseResponse(item, e) => write!(
+                f,
+                "Failed to parse download response: '{e:#?}' when attempting to download: '{item}'"
+            ),
+            DownloadError::IoError(item, e) => write!(
+                f,
+                "Encountered io error: '{e:#?}' when attempting to download: '{item}'"
+            ),
+            DownloadError::HttpError(item, e) => write!(
+                f,
+                "Encountered http error: '{e:#?}' when attempting to download: '{item}'"
+            ),
+            DownloadError::MissingFilename(item) => {
+                write!(f, "Could not get filename for: '{item}'")
+            }
+            DownloadError::CouldNotFindItem(item) => {
+                write!(f, "Could not find item: '{item}'")
+            }
+            DownloadError::MedafireMissingDDL(item) => {
+                write!(f, "Could not get DDL link from Nediafire: '{item}'")
+            }
+            DownloadError::MediafireRateLimited(item) => write!(
+                f,
+                "Mediafire is rate-limiting the download of '{item}', try again later"
+            ),
+            DownloadError::NoLongerAvailable(item) => {
+                write!(f, "'{item}' is no longer available on Modrinth")
+            }
+            DownloadError::HashMismatch(item) => {
+                write!(f, "The download of '{item}' doesn't match its hash, it may have been changed or corrupted")
+            }
+            DownloadError::UnsupportedSource(item, source) => {
+                write!(f, "'{item}' has the unsupported source '{source}'")
+            }
+            DownloadError::InvalidPath(item) => {
+                write!(f, "The path of '{item}' is not located in the modpack root")
+            }
+            DownloadError::Cancelled => write!(f, "Installation was cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for DownloadError {}
+
+#[derive(Debug, Clone)]
+enum RequestError {
+    HttpError(isahc::Error),
+    /// Contains the unix timestamp at which the rate limit resets if GitHub provided one
+    RateLimited(Option<u64>),
+    /// Only used internally by 'get_cacheable', never returned by 'get_cached'
+    TooLargeToCache(Box<CachedResponse>),
+}
+
+impl Display for RequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestError::HttpError(e) => write!(f, "{e}"),
+            RequestError::RateLimited(Some(reset)) => {
+                let now = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or_default();
+                let minutes = reset.saturating_sub(now).div_ceil(60).max(1);
+                write!(f, "GitHub rate limit hit, try again in {minutes} minutes")
+            }
+            RequestError::RateLimited(None) => {
+                write!(f, "GitHub rate limit hit, try again later")
+            }
+            RequestError::TooLargeToCache(_) => write!(f, "Response is too large to be cached"),
+        }
+    }
+}
+
+impl std::error::Error for RequestError {}
+
+impl From<isahc::Error> for RequestError {
+    fn from(value: isahc::Error) -> Self {
+        RequestError::HttpError(value)
+    }
+}
+
+#[derive(Debug)]
+enum LauncherProfileError {
+    IoError(std::io::Error),
+    InvalidJson(String),
+    ProfilesNotObject,
+    NoProfiles,
+    RootNotObject,
+    InvalidIcon(image::error::ImageError),
+    /// The profile was written but reading it back failed, see 'verify_launcher_profile'
+    NotCreated(String),
+}
+
+impl Display for LauncherProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LauncherProfileError::IoError(e) => write!(
+                f,
+                "Encountered IO error when creating launcher profile: {e}"
+            ),
+            LauncherProfileError::InvalidJson(e) => {
+                write!(f, "Invalid 'launcher_profiles.json': {e}")
+            }
+            LauncherProfileError::NoProfiles => {
+                write!(f, "'launcher_profiles.json' missing 'profiles' key")
+            }
+            LauncherProfileError::ProfilesNotObject => {
+                write!(f, "Expected 'launcher_profiles.profiles' to be 'object'")
+            }
+            LauncherProfileError::RootNotObject => {
+                write!(f, "Expected 'launcher_profiles' to be 'object'")
+            }
+            LauncherProfileError::InvalidIcon(e) => write!(
+                f,
+                "Encountered image error when creating launcher profile: {e}"
+            ),
+            LauncherProfileError::NotCreated(e) => {
+                write!(f, "The launcher profile wasn't created correctly: {e}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LauncherProfileError {}
+
+impl From<std::io::Error> for LauncherProfileError {
+    fn from(value: std::io::Error) -> Self {
+        LauncherProfileError::IoError(value)
+    }
+}
+
+impl From<serde_json::Error> for LauncherProfileError {
+    fn from(value: serde_json::Error) -> Self {
+        LauncherProfileError::InvalidJson(value.to_string())
+    }
+}
+
+impl From<image::error::ImageError> for LauncherProfileError {
+    fn from(value: image::error::ImageError) -> Self {
+        LauncherProfileError::InvalidIcon(value)
+    }
+}
+
+/// Error of 'init', 'install' and the other public functions, lets callers react to the kind of failure,
+/// e.g. the GUI only offers a retry for 'Network'
+#[derive(Debug, Clone, PartialEq)]
+pub enum InstallError {
+    /// A request failed or returned an error code, retrying later may help
+    Network(String),
+    /// A response or local file isn't valid json or doesn't match the expected structure
+    Parse(String),
+    /// The manifest parsed but breaks one or more rules, see 'validate_manifest'
+    InvalidManifest(String),
+    Io(String),
+    UnsupportedManifestVersion(i32),
+    /// The manifest requires a newer installer, contains the modpack and the required version
+    InstallerOutdated(String, String),
+    /// The files of the launcher weren't found, e.g. because it isn't installed
+    LauncherNotFound(String),
+    /// The profile has no launcher to install into
+    NoLauncher,
+    Cancelled,
+    Other(String),
+}
+
+impl Display for InstallError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InstallError::Network(e)
+            | InstallError::Parse(e)
+            | InstallError::InvalidManifest(e)
+            | InstallError::Io(e)
+            | InstallError::LauncherNotFound(e)
+            | InstallError::Other(e) => write!(f, "{e}"),
+            InstallError::UnsupportedManifestVersion(version) => {
+                write!(f, "Unsupported manifest version '{version}'!")
+            }
+            InstallError::InstallerOutdated(modpack, min_version) => write!(
+                f,
+                "'{modpack}' requires installer version {min_version} or newer but this is version {}, please update your installer: {INSTALLER_RELEASES}",
+                env!("CARGO_PKG_VERSION")
+            ),
+            InstallError::NoLauncher => write!(f, "No launcher selected, select one in the settings first"),
+            InstallError::Cancelled => write!(f, "Installation was cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for InstallError {}
+
+impl From<DownloadError> for InstallError {
+    fn from(value: DownloadError) -> Self {
+        match value {
+            DownloadError::FailedToParseResponse(..) => InstallError::Parse(value.to_string()),
+            DownloadError::IoError(..) => InstallError::Io(value.to_string()),
+            DownloadError::UnsupportedSource(..) | DownloadError::InvalidPath(..) => {
+                InstallError::InvalidManifest(value.to_string())
+            }
+            DownloadError::Cancelled => InstallError::Cancelled,
+            _ => InstallError::Network(value.to_string()),
+        }
+    }
+}
+
+impl From<RequestError> for InstallError {
+    fn from(value: RequestError) -> Self {
+        InstallError::Network(value.to_string())
+    }
+}
+
+impl From<LauncherProfileError> for InstallError {
+    fn from(value: LauncherProfileError) -> Self {
+        match value {
+            LauncherProfileError::IoError(ref e) if e.kind() == std::io::ErrorKind::NotFound => {
+                InstallError::LauncherNotFound(value.to_string())
+            }
+            LauncherProfileError::IoError(_) => InstallError::Io(value.to_string()),
+            LauncherProfileError::NotCreated(_) => InstallError::Other(value.to_string()),
+            _ => InstallError::Parse(value.to_string()),
+        }
+    }
+}
+
+
+/// Decodes '%XX' escapes, invalid escapes are kept as is
+fn percent_decode_bytes(value: &str) -> Vec<u8> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && i + 2 < bytes.len()
+            && bytes[i + 1].is_ascii_hexdigit()
+            && bytes[i + 2].is_ascii_hexdigit()
+        {
+            // both bytes are ascii so this can't split a char
+            decoded.push(u8::from_str_radix(&value[i + 1..i + 3], 16).unwrap());
+            i += 3;
+            continue;
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    decoded
+}
+
+fn percent_decode(value: &str) -> String {
+    String::from_utf8_lossy(&percent_decode_bytes(value)).to_string()
+}
+
+/// Gets the filename from the last url segment, decoded so 'My%20Mod.jar' becomes 'My Mod.jar'
+fn filename_from_url(url: &str) -> Option<String> {
+    url.split('/')
+        .next_back()
+        // an encoded '/' must not be able to escape the dist directory
+        .map(|v| percent_decode(v).replace(['/', '\\'], "_"))
+}
+
+/// Splits a header value at every ';' that isn't inside of a quoted string
+fn split_header_params(value: &str) -> Vec<&str> {
+    let mut params = vec![];
+    let mut start = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ';' if !quoted => {
+                params.push(&value[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    params.push(&value[start..]);
+    params
+}
+
+/// Removes the quotes and '\' escapes of a quoted string, unquoted values are returned as is
+fn unquote(value: &str) -> String {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(v) => {
+            let mut unquoted = String::with_capacity(v.len());
+            let mut chars = v.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => unquoted.extend(chars.next()),
+                    c => unquoted.push(c),
+                }
+            }
+            unquoted
+        }
+        None => value.to_string(),
+    }
+}
+
+/// Decodes an RFC 5987 value like "UTF-8''M%C3%B6d.jar", only UTF-8 and ISO-8859-1 are supported
+fn decode_ext_value(value: &str) -> Option<String> {
+    let mut parts = unquote(value).splitn(3, '\'').map(String::from).collect::<Vec<_>>();
+    if parts.len() != 3 {
+        return None;
+    }
+    let encoded = parts.pop().unwrap();
+    let decoded = percent_decode_bytes(&encoded);
+    match parts[0].to_ascii_lowercase().as_str() {
+        "utf-8" => String::from_utf8(decoded).ok(),
+        "iso-8859-1" => Some(decoded.into_iter().map(char::from).collect()),
+        _ => None,
+    }
+}
+
+/// Gets the filename from a 'content-disposition' header value.
+/// The extended 'filename*' is preferred over 'filename' which may be quoted or unquoted.
+fn parse_content_disposition(value: &str) -> Option<String> {
+    let mut filename = None;
+    let mut extended = None;
+    for param in split_header_params(value) {
+        let (key, value) = match param.split_once('=') {
+            Some(v) => v,
+            None => continue,
+        };
+        match key.trim().to_ascii_lowercase().as_str() {
+            "filename*" => extended = decode_ext_value(value.trim()),
+            "filename" => filename = Some(unquote(value.trim())),
+            _ => {}
+        }
+    }
+    extended
+        .or(filename)
+        // a filename must not be able to escape the dist directory
+        .map(|v| v.replace(['/', '\\'], "_"))
+        .filter(|v| !v.is_empty() && v != "." && v != "..")
+}
+
+fn get_filename(headers: &HeaderMap<HeaderValue>, url: &str) -> Result<String, DownloadError> {
+    // header values aren't guaranteed to be ascii, some servers send the raw utf-8 filename
+    let filename = match headers
+        .get("content-disposition")
+        .and_then(|v| parse_content_disposition(&String::from_utf8_lossy(v.as_bytes())))
+    {
+        Some(v) => v,
+        None => match filename_from_url(url) {
+            Some(v) => v,
+            None => return Err(DownloadError::MissingFilename(url.to_string())),
+        },
+    };
+    Ok(filename)
+}
+
+/// Writes the loader json and the dummy jar the vanilla launcher expects into the 'versions' folder of 'root'.
+/// 'force' rewrites both even if they look fine, e.g. to repair a loader install the user broke.
+async fn download_loader_json(
+    url: &str,
+    loader_name: &str,
+    root: &Path,
+    http_client: &CachedHttpClient,
+    check_upstream: bool,
+    force: bool,
+) -> Result<LoaderStatus, DownloadError> {
+    let loader_path = root.join(Path::new(&format!("versions/{}", &loader_name)));
+    let json_path = loader_path.join(Path::new(&format!("{}.json", &loader_name)));
+    if json_path.exists() && !check_upstream && !force {
+        return Ok(LoaderStatus::Present);
+    }
+    // Not cached as checking upstream has to see a republished profile
+    let json = match http_client.get_nocache(url).await {
+        Ok(mut resp) if resp.status().is_success() => match resp.text().await {
+            Ok(v) => Ok(v),
+            Err(e) => Err(DownloadError::IoError(loader_name.to_string(), e)),
+        },
+        Ok(resp) => Err(DownloadError::Non200StatusCode(loader_name.to_string(), resp.status().as_u16())),
+        Err(e) => Err(DownloadError::HttpError(loader_name.to_string(), e)),
+    };
+    let existing = fs::read(&json_path).ok().filter(|_| !force);
+    let json = match json {
+        Ok(v) => v,
+        // Only the upstream check failed, the installed loader still works
+        Err(e) if existing.is_some() => {
+            warn!("Failed to check loader profile '{loader_name}' upstream, keeping it: {e}");
+            return Ok(LoaderStatus::Present);
+        }
+        Err(e) => return Err(e),
+    };
+    // Fabric occasionally republishes profiles without changing the version, so compare the contents
+    if let Some(existing) = existing {
+        if sha256_hex(&existing) == sha256_hex(json.as_bytes()) {
+            return Ok(LoaderStatus::Present);
+        }
+        info!("Loader profile '{loader_name}' changed upstream, replacing it");
+    }
+    let write = || -> Result<(), std::io::Error> {
+        fs::create_dir_all(&loader_path)?;
+        write_atomic(&json_path, json)?;
+        fs::write(loader_path.join(Path::new(&format!("{}.jar", &loader_name))), "")
+    };
+    match write() {
+        Ok(_) => Ok(LoaderStatus::Downloaded(loader_path)),
+        Err(e) => Err(DownloadError::IoError(loader_name.to_string(), e)),
+    }
+}
+
+/// What 'download_file' downloads and where to, taken from the item being installed
+struct FileDownload<'a> {
+    name: &'a str,
+    id: &'a str,
+    /// Zero padded prefix of the filename, see 'Mod::priority'
+    priority: Option<i32>,
+    url: &'a str,
+    headers: &'a [(&'a str, &'a str)],
+    /// Skips verifying the TLS certificate, see 'allows_insecure_tls'
+    insecure: bool,
+    dist: &'a Path,
+}
+
+impl<'a> FileDownload<'a> {
+    /// Download of 'url' for 'item' without extra headers
+    fn new<T: Downloadable>(item: &'a T, url: &'a str, dist: &'a Path) -> FileDownload<'a> {
+        FileDownload {
+            name: item.get_name(),
+            id: item.get_id(),
+            priority: *item.get_priority(),
+            url,
+            headers: &[],
+            insecure: false,
+            dist,
+        }
+    }
+}
+
+/// Downloads 'download.url' into 'download.dist'. If the same url was already downloaded during this install the
+/// existing file is linked (or copied) to a path of its own instead of being fetched again.
+async fn download_file<F: FnOnce(&HeaderMap<HeaderValue>) -> Result<String, DownloadError>>(
+    download: FileDownload<'_>,
+    http_client: &CachedHttpClient,
+    filename: F,
+) -> Result<PathBuf, DownloadError> {
+    let FileDownload {
+        name,
+        id,
+        priority,
+        url,
+        headers,
+        insecure,
+        dist,
+    } = download;
+    let entry = http_client
+        .downloads
+        .lock()
+        .unwrap()
+        .entry(url.to_string())
+        .or_default()
+        .clone();
+    // Holding the entry lock makes concurrent downloads of the same url wait for the first one
+    let mut downloaded = entry.lock().await;
+    if let Some(existing) = downloaded.as_ref().filter(|x| x.exists()) {
+        // Every item gets its own file so disabling or removing one of them can't take the file of the other
+        let final_dist = claim_path(
+            &http_client.claimed_paths,
+            dist.join(existing.file_name().unwrap()), // downloaded paths always have a filename
+            id,
+        );
+        info!("Reusing {existing:#?} for '{name}'");
+        match fs::create_dir_all(dist) {
+            Ok(_) => (),
+            Err(e) => return Err(DownloadError::IoError(name.to_string(), e)),
+        };
+        if fs::hard_link(existing, &final_dist).is_err() {
+            match fs::copy(existing, &final_dist) {
+                Ok(_) => (),
+                Err(e) => return Err(DownloadError::IoError(name.to_string(), e)),
+            };
+        }
+        return Ok(final_dist);
+    }
+    let resp = if insecure {
+        warn!("Downloading '{name}' without verifying its TLS certificate");
+        http_client.insecure(url, headers).await
+    } else if headers.is_empty() {
+        http_client.get_nocache(url).await
+    } else {
+        http_client.with_headers(url, headers).await
+    };
+    let mut resp = match resp {
+        Ok(v) => v,
+        Err(e) => return Err(DownloadError::HttpError(name.to_string(), e)),
+    };
+    let filename = match priority {
+        Some(priority) => format!("{priority:02}_{}", filename(resp.headers())?),
+        None => filename(resp.headers())?,
+    };
+    match fs::create_dir_all(dist) {
+        Ok(_) => (),
+        Err(e) => return Err(DownloadError::IoError(name.to_string(), e)),
+    }
+    let final_dist = claim_path(&http_client.claimed_paths, dist.join(filename), id);
+    info!("Writing '{}' to '{:#?}'", name, final_dist);
+    let contents = match resp.bytes().await {
+        Ok(bytes) => bytes,
+        Err(e) => return Err(DownloadError::IoError(name.to_string(), e)),
+    };
+    let store = http_client.store.lock().unwrap().clone();
+    let written = match store {
+        Some(store) => write_to_store(&store, &contents, &final_dist),
+        None => write_atomic(&final_dist, contents),
+    };
+    match written {
+        Ok(_) => (),
+        Err(e) => return Err(DownloadError::IoError(name.to_string(), e)),
+    };
+    *downloaded = Some(final_dist.clone());
+    Ok(final_dist)
+}
+
+/// Checks that 'url' can be downloaded using a HEAD request.
+/// Some hosts don't allow HEAD requests so a rejected one is retried as GET, the body is never read.
+async fn probe_url(
+    name: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    http_client: &CachedHttpClient,
+) -> Result<(), DownloadError> {
+    let resp = match http_client.head(url, headers).await {
+        Ok(v) => v,
+        Err(e) => return Err(DownloadError::HttpError(name.to_string(), e)),
+    };
+    let status = if resp.status() == StatusCode::METHOD_NOT_ALLOWED {
+        match http_client.with_headers(url, headers).await {
+            Ok(v) => v.status(),
+            Err(e) => return Err(DownloadError::HttpError(name.to_string(), e)),
+        }
+    } else {
+        resp.status()
+    };
+    if status.is_success() {
+        Ok(())
+    } else {
+        Err(DownloadError::Non200StatusCode(name.to_string(), status.as_u16()))
+    }
+}
+
+/// Returns the temporary path used while writing 'path', it's next to 'path' so renaming it is atomic
+fn part_path(path: &Path) -> PathBuf {
+    let mut filename = path.file_name().unwrap_or_default().to_os_string();
+    filename.push(".part");
+    path.with_file_name(filename)
+}
+
+/// Writes 'contents' to a temporary file and renames it to 'path' once complete.
+/// This way a file at 'path' is never truncated, even if the installer is killed while writing.
+fn write_atomic<C: AsRef<[u8]>>(path: &Path, contents: C) -> Result<(), std::io::Error> {
+    let tmp = part_path(path);
+    if let Err(e) = fs::write(&tmp, contents) {
+        let _ = fs::remove_file(&tmp);
+        return Err(e);
+    }
+    fs::rename(&tmp, path)
+}
+
+/// Parses 'contents' as JSON, errors name the offending field relative to 'root', e.g. 'manifest.mods[3].version'
+fn parse_json<T: DeserializeOwned>(contents: &str, root: &str) -> Result<T, String> {
+    let deserializer = &mut serde_json::Deserializer::from_str(contents);
+    match serde_path_to_error::deserialize(deserializer) {
+        Ok(v) => Ok(v),
+        Err(e) => Err(describe_json_error(e, root)),
+    }
+}
+
+/// Same as 'parse_json' for an already parsed value
+fn parse_json_value<T: DeserializeOwned>(value: JsonValue, root: &str) -> Result<T, String> {
+    match serde_path_to_error::deserialize(value) {
+        Ok(v) => Ok(v),
+        Err(e) => Err(describe_json_error(e, root)),
+    }
+}
+
+fn describe_json_error(e: serde_path_to_error::Error<serde_json::Error>, root: &str) -> String {
+    let path = e.path().to_string();
+    let location = if path == "." {
+        root.to_string()
+    } else {
+        format!("{root}.{path}")
+    };
+    format!("Invalid value at '{location}': {}", e.inner())
+}
+
+fn sha256_hex(contents: &[u8]) -> String {
+    Sha256::digest(contents)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Writes 'contents' to '<store>/<sha256>.<ext>' once and hard links it to 'path'.
+/// Falls back to a plain copy if the filesystem doesn't support hard links (or 'path' is on another drive).
+fn write_to_store(store: &Path, contents: &[u8], path: &Path) -> Result<(), std::io::Error> {
+    let hash = sha256_hex(contents);
+    let stored = match path.extension() {
+        Some(ext) => store.join(format!("{hash}.{}", ext.to_string_lossy())),
+        None => store.join(&hash),
+    };
+    if !stored.exists() {
+        fs::create_dir_all(store)?;
+        // Written under a temporary name so an interrupted install can't leave a broken file in the store
+        // the item filename is unique within this install so concurrent writes of the same hash don't clash
+        let tmp = store.join(format!(
+            "{hash}-{}.part",
+            path.file_name().unwrap_or_default().to_string_lossy()
+        ));
+        PENDING_STORE_WRITES.fetch_add(1, Ordering::SeqCst);
+        let written = fs::write(&tmp, contents).and_then(|_| fs::rename(&tmp, &stored));
+        PENDING_STORE_WRITES.fetch_sub(1, Ordering::SeqCst);
+        written?;
+    }
+    // 'hard_link' fails if the destination already exists
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    if let Err(e) = fs::hard_link(&stored, path) {
+        warn!("Failed to link {stored:#?} to {path:#?}, copying instead: {e}");
+        write_atomic(path, contents)?;
+    }
+    Ok(())
+}
+
+/// Store writes which haven't been renamed to their final name yet
+static PENDING_STORE_WRITES: AtomicUsize = AtomicUsize::new(0);
+
+/// Waits for in-flight writes to the shared store, called when the window is closed so they aren't cut off.
+/// Writes that don't finish within 'timeout' leave a '.part' file which 'clean_store' removes on the next start.
+fn flush_store(timeout: Duration) {
+    let start = Instant::now();
+    while PENDING_STORE_WRITES.load(Ordering::SeqCst) != 0 {
+        if start.elapsed() >= timeout {
+            warn!("Store writes didn't finish within {timeout:?}, exiting anyway");
+            return;
+        }
+        sleep(Duration::from_millis(10));
+    }
+}
+
+/// Removes '.part' files of store writes which were interrupted, e.g. because the installer was killed.
+/// Entries only get their '<sha256>.<ext>' name once completely written so every other file is valid.
+fn clean_store(store: &Path) {
+    let entries = match fs::read_dir(store) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().is_some_and(|x| x == "part") {
+            match fs::remove_file(&path) {
+                Ok(_) => info!("Removed partial store entry {path:#?}"),
+                Err(e) => warn!("Failed to remove partial store entry {path:#?}: {e}"),
+            }
+        }
+    }
+}
+
+/// Reserves 'path' for this install. If another item already claimed it or a file not written by this install is in
+/// the way the item 'id' (and a counter if needed) is appended to the file stem, e.g. 'mod.jar' becomes 'mod-default.jar'.
+fn claim_path(claimed_paths: &Mutex<HashSet<PathBuf>>, path: PathBuf, id: &str) -> PathBuf {
+    let mut claimed_paths = claimed_paths.lock().unwrap();
+    let mut candidate = path.clone();
+    let mut i = 1;
+    while claimed_paths.contains(&candidate) || candidate.exists() {
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let suffix = if i == 1 {
+            id.to_string()
+        } else {
+            format!("{id}-{i}")
+        };
+        let filename = match path.extension() {
+            Some(ext) => format!("{stem}-{suffix}.{}", ext.to_string_lossy()),
+            None => format!("{stem}-{suffix}"),
+        };
+        candidate = path.with_file_name(filename);
+        i += 1;
+    }
+    if candidate != path {
+        warn!("{path:#?} is already in use, using {candidate:#?} instead");
+    }
+    claimed_paths.insert(candidate.clone());
+    candidate
+}
+
+/// Returns the directory an item of 'type' is downloaded into
+fn get_item_dist<T: Downloadable>(item: &T, modpack_root: &Path, r#type: &str) -> PathBuf {
+    let dist = match r#type {
+        "mod" => modpack_root.join(Path::new("mods")),
+        "resourcepack" => modpack_root.join(Path::new("resourcepacks")),
+        "shaderpack" => modpack_root.join(Path::new("shaderpacks")),
+        _ => panic!("Unsupported item type: '{}'???", r#type), // this should be impossible
+    };
+    match item.get_subfolder() {
+        Some(subfolder) => dist.join(subfolder),
+        None => dist,
+    }
+}
+
+async fn download_from_ddl<T: Downloadable + Debug>(
+    item: &T,
+    modpack_root: &Path,
+    r#type: &str,
+    http_client: &CachedHttpClient,
+) -> Result<PathBuf, DownloadError> {
+    let headers: Vec<(&str, &str)> = match item.get_headers() {
+        Some(headers) => headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect(),
+        None => vec![],
+    };
+    let dist = get_item_dist(item, modpack_root, r#type);
+    download_file(
+        FileDownload {
+            headers: &headers,
+            insecure: allows_insecure_tls(item.get_location()),
+            ..FileDownload::new(item, item.get_location(), &dist)
+        },
+        http_client,
+        |headers| get_filename(headers, item.get_location()),
+    )
+    .await
+}
+
+/// Encodes a list of values as a url-escaped JSON array for Modrinth query params
+fn modrinth_query_list(values: &[&str]) -> String {
+    serde_json::to_string(values)
+        .unwrap()
+        .replace('[', "%5B")
+        .replace(']', "%5D")
+        .replace('"', "%22")
+        .replace(',', "%2C")
+}
+
+async fn get_modrinth_versions<T: Downloadable + Debug>(
+    item: &T,
+    url: &str,
+    http_client: &CachedHttpClient,
+) -> Result<Vec<ModrinthObject>, DownloadError> {
+    let mut resp = match http_client.get_nocache(url).await {
+        Ok(v) => v,
+        Err(e) => {
+            return Err(DownloadError::HttpError(item.get_name().to_string(), e));
+        }
+    };
+    // Modrinth answers with 404 for deleted projects, 410 is used by some mirrors
+    if resp.status() == StatusCode::NOT_FOUND || resp.status() == StatusCode::GONE {
+        return Err(DownloadError::NoLongerAvailable(item.get_name().to_string()));
+    }
+    if resp.status() != StatusCode::OK {
+        return Err(DownloadError::Non200StatusCode(
+            item.get_name().to_string(),
+            resp.status().as_u16(),
+        ));
+    }
+    let resp_text = match resp.text().await {
+        Ok(v) => v,
+        Err(e) => return Err(DownloadError::IoError(item.get_name().to_string(), e)),
+    };
+    match serde_json::from_str(&resp_text) {
+        Ok(v) => Ok(v),
+        Err(e) => Err(DownloadError::FailedToParseResponse(
+            item.get_name().to_string(),
+            e,
+        )),
+    }
+}
+
+/// Loaders whose mods run on 'loader_type', most preferred first. Quilt is able to load Fabric mods.
+fn compatible_loaders(loader_type: &str) -> Vec<&str> {
+    match loader_type {
+        "quilt" => vec!["quilt", "fabric"],
+        _ => vec![loader_type],
+    }
+}
+
+fn find_modrinth_version<'a, T: Downloadable>(
+    item: &T,
+    versions: &'a [ModrinthObject],
+    loader_type: &str,
+    r#type: &str,
+) -> Option<&'a ModrinthObject> {
+    // Checked one loader at a time so a native file wins over one for a compatible loader
+    compatible_loaders(loader_type).into_iter().find_map(|loader| {
+        versions.iter().find(|_mod| {
+            &_mod.version_number == item.get_version()
+                && (_mod.loaders.contains(&String::from("minecraft"))
+                    || _mod.loaders.contains(&String::from(loader))
+                    || r#type == "shaderpack")
+        })
+    })
+}
+
+/// Environment of a Modrinth project, each is "required", "optional", "unsupported" or "unknown"
+#[derive(Debug, Deserialize)]
+struct ModrinthProjectSides {
+    client_side: String,
+    server_side: String,
+}
+
+/// Returns whether the Modrinth project of 'item' can be installed on 'side', it's assumed to if that can't be fetched
+async fn modrinth_supports_side<T: Downloadable>(item: &T, side: Side, http_client: &CachedHttpClient) -> bool {
+    let url = format!("{}project/{}", http_client.endpoints.modrinth_api, item.get_location());
+    let mut resp = match http_client.get_async(url).await {
+        Ok(v) if v.status() == StatusCode::OK => v,
+        _ => return true,
+    };
+    let project: ModrinthProjectSides = match parse_json(&resp.text().await.unwrap_or_default(), "project") {
+        Ok(v) => v,
+        Err(_) => return true,
+    };
+    match side {
+        Side::Client => project.client_side != "unsupported",
+        Side::Server => project.server_side != "unsupported",
+    }
+}
+
+/// Finds the file of the version 'item' pins that matches the loader and Minecraft version
+async fn resolve_modrinth_file<T: Downloadable + Debug>(
+    item: &T,
+    loader_type: &str,
+    minecraft_version: &str,
+    r#type: &str,
+    http_client: &CachedHttpClient,
+) -> Result<ModrinthFile, DownloadError> {
+    let versions_url = format!(
+        "{}project/{}/version",
+        http_client.endpoints.modrinth_api,
+        item.get_location()
+    );
+    // Shaderpacks are tagged with their shader loader (iris, optifine, ...) so only filter by game version for them
+    let filtered_url = match r#type {
+        "mod" => format!(
+            "{versions_url}?game_versions={}&loaders={}",
+            modrinth_query_list(&[minecraft_version]),
+            modrinth_query_list(&[compatible_loaders(loader_type), vec!["minecraft"]].concat())
+        ),
+        _ => format!(
+            "{versions_url}?game_versions={}",
+            modrinth_query_list(&[minecraft_version])
+        ),
+    };
+    let filtered = get_modrinth_versions(item, &filtered_url, http_client).await?;
+    // Not every project tags its versions correctly, so fall back to the unfiltered list
+    let unfiltered;
+    let version = match find_modrinth_version(item, &filtered, loader_type, r#type) {
+        Some(v) => v,
+        None => {
+            warn!(
+                "Could not find '{}' for Minecraft '{minecraft_version}', falling back to unfiltered versions",
+                item.get_name()
+            );
+            unfiltered = get_modrinth_versions(item, &versions_url, http_client).await?;
+            if unfiltered.is_empty() {
+                return Err(DownloadError::NoLongerAvailable(item.get_name().to_string()));
+            }
+            match find_modrinth_version(item, &unfiltered, loader_type, r#type) {
+                Some(v) => v,
+                None => return Err(DownloadError::CouldNotFindItem(item.get_name().to_string())),
+            }
+        }
+    };
+    match version.files.first() {
+        Some(file) => Ok(file.clone()),
+        None => Err(DownloadError::CouldNotFindItem(item.get_name().to_string())),
+    }
+}
+
+async fn download_from_modrinth<T: Downloadable + Debug>(
+    item: &T,
+    modpack_root: &Path,
+    loader_type: &str,
+    minecraft_version: &str,
+    r#type: &str,
+    http_client: &CachedHttpClient,
+) -> Result<PathBuf, DownloadError> {
+    let file = resolve_modrinth_file(item, loader_type, minecraft_version, r#type, http_client).await?;
+    let dist = get_item_dist(item, modpack_root, r#type);
+    download_file(
+        FileDownload::new(item, &file.url, &dist),
+        http_client,
+        |_| Ok(file.filename.clone()),
+    )
+    .await
+}
+
+/// Extracts the direct download link from the Mediafire page of 'item'.
+/// Mediafire serves a captcha page instead when it rate limits, this is retried with 'MEDIAFIRE_BACKOFF'.
+async fn get_mediafire_ddl<T: Downloadable + Debug>(
+    item: &T,
+    http_client: &CachedHttpClient,
+) -> Result<String, DownloadError> {
+    let re = Regex::new(r#"Download file"\s*href="(.*?)""#).unwrap(); // wont error pattern is valid
+    let mut backoff = MEDIAFIRE_BACKOFF.iter();
+    loop {
+        let mut resp = match http_client.get_nocache(item.get_location()).await {
+            Ok(v) => v,
+            Err(e) => {
+                return Err(DownloadError::HttpError(item.get_name().to_string(), e));
+            }
+        };
+        let rate_limited = if resp.status() == StatusCode::TOO_MANY_REQUESTS {
+            true
+        } else if resp.status() != StatusCode::OK {
+            return Err(DownloadError::Non200StatusCode(
+                item.get_name().to_string(),
+                resp.status().as_u16(),
+            ));
+        } else {
+            let mediafire = match resp.text().await {
+                Ok(v) => v,
+                Err(e) => return Err(DownloadError::IoError(item.get_name().to_string(), e)),
+            };
+            if let Some(v) = re.captures(&mediafire) {
+                return Ok(v[1].to_string());
+            }
+            let page = mediafire.to_lowercase();
+            MEDIAFIRE_INTERSTITIAL_MARKERS
+                .iter()
+                .any(|marker| page.contains(marker))
+        };
+        if !rate_limited {
+            return Err(DownloadError::MedafireMissingDDL(
+                item.get_name().to_string(),
+            ));
+        }
+        match backoff.next() {
+            Some(wait) => {
+                warn!(
+                    "Mediafire is rate limiting '{}', retrying in {}s",
+                    item.get_name(),
+                    wait.as_secs()
+                );
+                async_io::Timer::after(*wait).await;
+            }
+            None => {
+                return Err(DownloadError::MediafireRateLimited(
+                    item.get_name().to_string(),
+                ))
+            }
+        }
+    }
+}
+
+async fn download_from_mediafire<T: Downloadable + Debug>(
+    item: &T,
+    modpack_root: &Path,
+    r#type: &str,
+    http_client: &CachedHttpClient,
+) -> Result<PathBuf, DownloadError> {
+    let ddl = get_mediafire_ddl(item, http_client).await?;
+    let dist = get_item_dist(item, modpack_root, r#type);
+    download_file(FileDownload::new(item, &ddl, &dist), http_client, |headers| {
+        match headers
+            .get("content-disposition")
+            .and_then(|v| parse_content_disposition(&String::from_utf8_lossy(v.as_bytes())))
+        {
+            Some(v) => Ok(v),
+            None => Err(DownloadError::MissingFilename(item.get_name().to_string())),
+        }
+    })
+    .await
+}
+
+fn get_config_path() -> Result<PathBuf, InstallError> {
+    match get_sandbox_root().or_else(dirs::data_dir) {
+        Some(v) => Ok(v.join("Wynncraft Majestic Overhaul Installer")),
+        None => Err(InstallError::Io(String::from(DATA_DIR_ERROR))),
+    }
+}
+
+/// Returns the sandbox directory set through 'WC_OVHL_ROOT' if any.
+/// When set, launcher folders are resolved inside of it instead of the real ones so installs don't touch the users setup.
+fn get_sandbox_root() -> Option<PathBuf> {
+    match env::var_os("WC_OVHL_ROOT") {
+        Some(v) if !v.is_empty() => Some(PathBuf::from(v)),
+        _ => None,
+    }
+}
+
+fn get_app_data() -> Result<PathBuf, InstallError> {
+    if let Some(sandbox) = get_sandbox_root() {
+        return Ok(sandbox);
+    }
+    match env::consts::OS {
+        "linux" => dirs::home_dir(),
+        "windows" | "macos" => dirs::config_dir(),
+        // Unsupported OS, there's no known location of the launchers
+        _ => None,
+    }
+    .ok_or_else(|| InstallError::Io(String::from(DATA_DIR_ERROR)))
+}
+
+fn get_multimc_folder(multimc: &str) -> Result<PathBuf, InstallError> {
+    let path = match env::consts::OS {
+        _ if get_sandbox_root().is_some() => get_app_data()?.join(multimc),
+        "linux" => match dirs::data_dir() {
+            Some(v) => v.join(multimc),
+            None => return Err(InstallError::Io(String::from(DATA_DIR_ERROR))),
+        },
+        "windows" | "macos" => get_app_data()?.join(multimc),
+        _ => return Err(InstallError::Io(String::from(DATA_DIR_ERROR))),
+    };
+    match path.metadata() {
+        Ok(metadata) => {
+            if metadata.is_dir() && path.join("instances").is_dir() {
+                Ok(path)
+            } else {
+                Err(InstallError::LauncherNotFound(String::from(
+                    "MultiMC directory is not a valid directory!",
+                )))
+            }
+        }
+        Err(e) => Err(InstallError::LauncherNotFound(e.to_string())),
+    }
+}
+
+/// Returns the data directory of the Modrinth App, it contains a folder per profile in 'profiles'
+fn get_modrinth_folder() -> Result<PathBuf, InstallError> {
+    let path = match env::consts::OS {
+        _ if get_sandbox_root().is_some() => get_app_data()?.join("ModrinthApp"),
+        "linux" => match dirs::data_dir() {
+            Some(v) => v.join("ModrinthApp"),
+            None => return Err(InstallError::Io(String::from(DATA_DIR_ERROR))),
+        },
+        "windows" | "macos" => get_app_data()?.join("ModrinthApp"),
+        _ => return Err(InstallError::Io(String::from(DATA_DIR_ERROR))),
+    };
+    match path.metadata() {
+        Ok(metadata) => {
+            if metadata.is_dir() && path.join("profiles").is_dir() {
+                Ok(path)
+            } else {
+                Err(InstallError::LauncherNotFound(String::from(
+                    "Modrinth App directory is not a valid directory!",
+                )))
+            }
+        }
+        Err(e) => Err(InstallError::LauncherNotFound(e.to_string())),
+    }
+}
+
+fn get_minecraft_folder() -> Result<PathBuf, InstallError> {
+    Ok(get_minecraft_folder_in(&get_app_data()?))
+}
+
+/// Returns the vanilla launcher folder inside of 'app_data', see 'get_app_data'
+fn get_minecraft_folder_in(app_data: &Path) -> PathBuf {
+    if get_sandbox_root().is_some() {
+        app_data.join(".minecraft")
+    } else if env::consts::OS == "macos" {
+        app_data.join("minecraft")
+    } else {
+        app_data.join(".minecraft")
+    }
+}
+
+fn get_modpack_root(launcher: &Launcher, uuid: &str) -> PathBuf {
+    let root = get_modpack_dir(launcher, uuid);
+    fs::create_dir_all(&root).expect("Failed to create modpack folder");
+    root
+}
+
+/// Same as 'get_modpack_root' but doesn't create the folder, e.g. for showing where a modpack will be installed
+fn get_modpack_dir(launcher: &Launcher, uuid: &str) -> PathBuf {
+    match launcher {
+        Launcher::Vanilla(root) => root.join(Path::new(&format!(".WC_OVHL/{}", uuid))),
+        Launcher::MultiMC(root) => get_multimc_game_dir(&root.join(format!("instances/{uuid}"))),
+        // The Modrinth App uses the profile folder itself as the game directory
+        Launcher::Modrinth(root) => root.join(format!("profiles/{uuid}")),
+    }
+}
+
+/// Returns the game directory of a MultiMC instance.
+/// Depending on the fork and how the instance was created it's either 'minecraft' or '.minecraft', the latter is used if neither exists.
+fn get_multimc_game_dir(instance: &Path) -> PathBuf {
+    let dot_minecraft = instance.join(".minecraft");
+    let minecraft = instance.join("minecraft");
+    if minecraft.is_dir() && !dot_minecraft.is_dir() {
+        minecraft
+    } else {
+        dot_minecraft
+    }
+}
+
+/// Folders the game writes user data to, kept next to the modpack root if 'Config::separate_user_data' is set
+const USER_DATA_DIRS: [&str; 3] = ["saves", "screenshots", "logs"];
+
+/// Returns the folder next to the modpack root which holds the 'USER_DATA_DIRS' of a modpack, see 'link_user_data'.
+/// 'uninstall' doesn't touch it so worlds and screenshots survive reinstalls.
+fn get_user_data_dir(launcher: &Launcher, uuid: &str) -> PathBuf {
+    match launcher {
+        Launcher::Vanilla(root) | Launcher::MultiMC(root) | Launcher::Modrinth(root) => {
+            root.join(format!(".WC_OVHL/{uuid}-data"))
+        }
+    }
+}
+
+/// Replaces the 'USER_DATA_DIRS' in 'modpack_root' with links into 'user_data_dir'.
+/// Existing data is moved over first, unless the user data folder already has its own.
+fn link_user_data(modpack_root: &Path, user_data_dir: &Path) -> Result<(), std::io::Error> {
+    for dir in USER_DATA_DIRS {
+        let link = modpack_root.join(dir);
+        let target = user_data_dir.join(dir);
+        if link.symlink_metadata().is_ok_and(|x| x.file_type().is_symlink()) {
+            continue;
+        }
+        if link.is_dir() {
+            if target.exists() {
+                warn!("Not moving {link:#?} as {target:#?} already exists");
+                continue;
+            }
+            move_dir(&link, &target)?;
+        }
+        fs::create_dir_all(&target)?;
+        create_dir_link(&target, &link)?;
+    }
+    Ok(())
+}
+
+/// Removes the links created by 'link_user_data' so removing the modpack root can't reach the user data
+fn unlink_user_data(modpack_root: &Path) {
+    for dir in USER_DATA_DIRS {
+        let link = modpack_root.join(dir);
+        if link.symlink_metadata().is_ok_and(|x| x.file_type().is_symlink()) {
+            // Links to directories are files on Unix and directories on Windows
+            if let Err(e) = fs::remove_file(&link).or_else(|_| fs::remove_dir(&link)) {
+                warn!("Failed to remove link {link:#?}: {e}");
+            }
+        }
+    }
+}
+
+/// Creates a directory symlink, on Windows a junction as symlinks need admin rights there
+fn create_dir_link(target: &Path, link: &Path) -> Result<(), std::io::Error> {
+    #[cfg(not(target_os = "windows"))]
+    {
+        std::os::unix::fs::symlink(target, link)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        let status = std::process::Command::new("cmd")
+            .arg("/C")
+            .arg("mklink")
+            .arg("/J")
+            .arg(link)
+            .arg(target)
+            .status()?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(std::io::Error::other(format!("'mklink' failed with {status}")))
+        }
+    }
+}
+
+/// Removes what 'create_launcher_profile' created for 'uuid', the modpack folder itself is left alone
+fn remove_launcher_profile(launcher: &Launcher, uuid: &str) -> Result<(), LauncherProfileError> {
+    match launcher {
+        Launcher::Vanilla(root) => {
+            let lp_file_path = get_minecraft_folder_in(root).join("launcher_profiles.json");
+            if !lp_file_path.exists() {
+                return Ok(());
+            }
+            let mut lp_obj: JsonValue =
+                match parse_json(&fs::read_to_string(&lp_file_path)?, "launcher_profiles") {
+                    Ok(v) => v,
+                    Err(e) => return Err(LauncherProfileError::InvalidJson(e)),
+                };
+            match lp_obj.get_mut("profiles").and_then(|x| x.as_object_mut()) {
+                Some(profiles) => {
+                    if profiles.remove(uuid).is_none() {
+                        return Ok(());
+                    }
+                }
+                None => return Err(LauncherProfileError::ProfilesNotObject),
+            }
+            fs::write(&lp_file_path, serde_json::to_string(&lp_obj)?)?;
+        }
+        Launcher::MultiMC(root) => {
+            let instance = root.join(format!("instances/{uuid}"));
+            if instance.is_dir() {
+                fs::remove_dir_all(instance)?;
+            }
+            let icon = root.join(format!("icons/{uuid}.png"));
+            if icon.exists() {
+                fs::remove_file(icon)?;
+            }
+        }
+        Launcher::Modrinth(root) => {
+            let profile_dir = root.join(format!("profiles/{uuid}"));
+            for file in ["profile.json", "icon.png"] {
+                if profile_dir.join(file).exists() {
+                    fs::remove_file(profile_dir.join(file))?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn image_to_base64(img: &DynamicImage) -> String {
+    // The data uri is stored in 'launcher_profiles.json' so large icons would bloat it, 'resize' keeps the aspect ratio
+    let resized;
+    let img = if img.width() > MAX_ICON_SIZE || img.height() > MAX_ICON_SIZE {
+        resized = img.resize(MAX_ICON_SIZE, MAX_ICON_SIZE, FilterType::Lanczos3);
+        &resized
+    } else {
+        img
+    };
+    let mut image_data: Vec<u8> = Vec::new();
+    img.write_to(&mut Cursor::new(&mut image_data), ImageFormat::Png)
+        .unwrap();
+    let res_base64 = engine::general_purpose::STANDARD.encode(image_data);
+    format!("data:image/png;base64,{}", res_base64)
+}
+
+/// Creates or updates the launcher profile of the modpack.
+/// Returns why its memory differs from the one the modpack requested, see 'clamp_memory'.
+fn create_launcher_profile(
+    installer_profile: &InstallerProfile,
+    icon_img: Option<DynamicImage>,
+) -> Result<Vec<String>, LauncherProfileError> {
+    let now = SystemTime::now();
+    let now: DateTime<Utc> = now.into();
+    let now = now.to_rfc3339();
+    let manifest = &installer_profile.manifest;
+    let modpack_root = get_modpack_root(
+        installer_profile
+            .launcher
+            .as_ref()
+            .expect("No launcher selected!"), // should be impossible
+        &manifest.uuid,
+    );
+    // The manifest value is authoritative, the default must not end up below 'min_mem'
+    let max_mem = manifest.max_mem.or_else(|| {
+        get_default_max_mem().map(|v| v.max(manifest.min_mem.unwrap_or_default()))
+    });
+    // A typo like '20480' instead of '2048' would otherwise create a profile which fails to start
+    let (max_mem, min_mem, adjustments) = clamp_memory(max_mem, manifest.min_mem, get_system_ram());
+    for adjustment in &adjustments {
+        warn!("{adjustment}");
+    }
+    // Memory and java args the user tuned in their launcher are kept unless the modpack changed its own
+    let apply_jvm_settings = read_config()
+        .and_then(|config| config.force_manifest_jvm)
+        .unwrap_or(false)
+        || installer_profile.local_manifest.as_ref().is_none_or(|x| {
+            x.max_mem != manifest.max_mem
+                || x.min_mem != manifest.min_mem
+                || x.java_args != manifest.java_args
+        });
+    match installer_profile
+        .launcher
+        .as_ref()
+        .expect("Asked to create launcher profile without knowing launcher!") // should be impossible
+    {
+        Launcher::Vanilla(root) => {
+            if manifest.prelaunch_command.is_some() {
+                warn!("The vanilla launcher doesn't support pre-launch commands, ignoring 'prelaunch_command'");
+            }
+            let icon = if manifest.icon && icon_img.is_some() {
+                image_to_base64(
+                    icon_img
+                        .as_ref()
+                        .unwrap()
+                )
+            } else {
+                String::from("Furnace")
+            };
+            let mut jvm_args = String::new();
+            if manifest.java_args.is_none()
+                && (manifest.max_mem.is_some() || manifest.min_mem.is_some())
+            {
+                jvm_args += "XX:+UnlockExperimentalVMOptions -XX:+UseG1GC -XX:G1NewSizePercent=20 -XX:G1ReservePercent=20 -XX:MaxGCPauseMillis=50 -XX:G1HeapRegionSize=32M";
+            }
+            if let Some(x) = &manifest.java_args {
+                jvm_args += &x
+            }
+            if let Some(x) = max_mem {
+                jvm_args += &format!(" -Xmx{}M", x)
+            }
+            if let Some(x) = min_mem {
+                jvm_args += &format!(" -Xms{}M", x)
+            }
+            let profile = LauncherProfile {
+                lastUsed: now.to_string(),
+                lastVersionId: match &manifest.loader.r#type[..] {
+                    "fabric" => format!(
+                        "fabric-loader-{}-{}",
+                        &manifest.loader.version, &manifest.loader.minecraft_version
+                    ),
+                    "quilt" => format!(
+                        "quilt-loader-{}-{}",
+                        &manifest.loader.version, &manifest.loader.minecraft_version
+                    ),
+                    _ => panic!("Invalid loader"),
+                },
+                created: now,
+                name: manifest.name.clone(),
+                icon: Some(icon),
+                r#type: String::from("custom"),
+                gameDir: Some(modpack_root.to_str().unwrap().to_string()),
+                javaDir: None,
+                javaArgs: if jvm_args.is_empty() {
+                    None
+                } else {
+                    Some(jvm_args)
+                },
+                logConfig: None,
+                logConfigIsXML: None,
+                resolution: None,
+            };
+            let lp_file_path = get_minecraft_folder_in(root).join(Path::new("launcher_profiles.json"));
+            let lp_exists = lp_file_path.exists();
+            // The launcher only creates this file when it's first opened, so start from an empty one
+            let mut lp_obj: JsonValue = if lp_exists {
+                match parse_json(&fs::read_to_string(&lp_file_path)?, "launcher_profiles") {
+                    Ok(v) => v,
+                    Err(e) => return Err(LauncherProfileError::InvalidJson(e)),
+                }
+            } else {
+                info!("'launcher_profiles.json' doesn't exist yet, creating it");
+                serde_json::json!({
+                    "profiles": {},
+                    "settings": {},
+                    "version": 3,
+                })
+            };
+            match lp_obj {
+                JsonValue::Object(ref obj) => match obj
+                    .get("profiles")
+                    .ok_or(LauncherProfileError::NoProfiles)?
+                {
+                    JsonValue::Object(_) => {
+                        let profiles = lp_obj.get_mut("profiles").unwrap().as_object_mut().unwrap();
+                        let java_args = profile.javaArgs.clone();
+                        let profile = if profiles.contains_key(&manifest.uuid) {
+                            let mut profile: LauncherProfile = match parse_json_value(
+                                profiles.get(&manifest.uuid).unwrap().clone(),
+                                &format!("launcher_profiles.profiles.{}", manifest.uuid),
+                            ) {
+                                Ok(v) => v,
+                                Err(e) => return Err(LauncherProfileError::InvalidJson(e)),
+                            };
+                            profile.lastVersionId = match &manifest.loader.r#type[..] {
+                                "fabric" => format!(
+                                    "fabric-loader-{}-{}",
+                                    &manifest.loader.version, &manifest.loader.minecraft_version
+                                ),
+                                "quilt" => format!(
+                                    "quilt-loader-{}-{}",
+                                    &manifest.loader.version, &manifest.loader.minecraft_version
+                                ),
+                                _ => panic!("Invalid loader"),
+                            };
+                            if apply_jvm_settings {
+                                profile.javaArgs = java_args;
+                            }
+                            profile
+                        } else {
+                            profile
+                        };
+                        profiles.insert(manifest.uuid.clone(), serde_json::to_value(profile)?);
+                    },
+                    _ => return Err(LauncherProfileError::ProfilesNotObject),
+                },
+                _ => return Err(LauncherProfileError::RootNotObject),
+            };
+            let lp_contents = serde_json::to_string(&lp_obj)?;
+            // The official launcher manages this file too so keep a copy of it in case writing goes wrong
+            if !lp_exists {
+                if let Some(parent) = lp_file_path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&lp_file_path, lp_contents)?;
+            } else {
+                // Timestamped so a later install doesn't overwrite the copy of a working file
+                let backup_path = lp_file_path.with_file_name(format!(
+                    "launcher_profiles.json.{}.bak",
+                    Utc::now().format("%Y%m%d%H%M%S")
+                ));
+                fs::copy(&lp_file_path, &backup_path)?;
+                info!("Backed up 'launcher_profiles.json' to {backup_path:#?}");
+                if let Err(e) = fs::write(&lp_file_path, lp_contents) {
+                    error!("Failed to write 'launcher_profiles.json', restoring backup: {e}");
+                    fs::copy(&backup_path, &lp_file_path)?;
+                    return Err(e.into());
+                }
+            }
+        }
+        Launcher::MultiMC(root) => {
+            let instance_cfg_path = root.join(Path::new(&format!(
+                "instances/{}/instance.cfg",
+                manifest.uuid
+            )));
+            let pack = MMCPack {
+                components: vec![
+                    MMCComponent {
+                        uid: String::from("net.minecraft"),
+                        version: manifest.loader.minecraft_version.to_string(),
+                        cachedVolatile: None,
+                        dependencyOnly: None,
+                        important: Some(true),
+                    },
+                    match &manifest.loader.r#type[..] {
+                        "fabric" => MMCComponent {
+                            uid: String::from("net.fabricmc.fabric-loader"),
+                            version: manifest.loader.version.to_string(),
+                            cachedVolatile: None,
+                            dependencyOnly: None,
+                            important: None,
+                        },
+                        "quilt" => MMCComponent {
+                            uid: String::from("org.quiltmc.quilt-loader"),
+                            version: manifest.loader.version.to_string(),
+                            cachedVolatile: None,
+                            dependencyOnly: None,
+                            important: None,
+                        },
+                        _ => panic!("Invalid loader"),
+                    },
+                ],
+                formatVersion: 1,
+            };
+            fs::write(
+                root.join(Path::new(&format!(
+                    "instances/{}/mmc-pack.json",
+                    manifest.uuid
+                ))),
+                serde_json::to_string(&pack)?,
+            )?;
+            if !instance_cfg_path.exists() {
+                let jvm_args = match manifest.java_args.as_ref() {
+                    Some(v) => format!("\nJvmArgs={}\nOverrideJavaArgs=true", v),
+                    None => String::new(),
+                };
+                let max_mem = match max_mem {
+                    Some(v) => format!("\nMaxMemAlloc={}", v),
+                    None => String::new(),
+                };
+                let min_mem = match min_mem {
+                    Some(v) => format!("\nMinMemAlloc={}", v),
+                    None => String::new(),
+                };
+                let override_mem = if max_mem.is_empty() && min_mem.is_empty() {
+                    ""
+                } else {
+                    "\nOverrideMemory=true"
+                };
+                let prelaunch_command = match manifest.prelaunch_command.as_ref() {
+                    Some(v) => format!("\nPreLaunchCommand={}\nOverrideCommands=true", v),
+                    None => String::new(),
+                };
+                fs::write(
+                    root.join(instance_cfg_path),
+                    format!(
+                        "InstanceType=OneSix\niconKey={}\nname={}{}{}{}{}{}",
+                        manifest.uuid, manifest.name, max_mem, min_mem, override_mem, jvm_args, prelaunch_command
+                    ),
+                )?;
+                // Without an icon MultiMC falls back to its default one
+                if let Some(icon_img) = icon_img {
+                    icon_img.save(root.join(Path::new(&format!("icons/{}.png", manifest.uuid))))?;
+                }
+            } else if apply_jvm_settings {
+                let mut values = vec![];
+                if let Some(v) = max_mem {
+                    values.push(("MaxMemAlloc", v.to_string()));
+                }
+                if let Some(v) = min_mem {
+                    values.push(("MinMemAlloc", v.to_string()));
+                }
+                values.push((
+                    "OverrideMemory",
+                    (max_mem.is_some() || min_mem.is_some()).to_string(),
+                ));
+                if let Some(v) = &manifest.java_args {
+                    values.push(("JvmArgs", v.clone()));
+                }
+                values.push(("OverrideJavaArgs", manifest.java_args.is_some().to_string()));
+                let contents = fs::read_to_string(&instance_cfg_path)?;
+                fs::write(&instance_cfg_path, set_cfg_values(&contents, &values))?;
+            }
+        }
+        Launcher::Modrinth(root) => {
+            let profile_dir = root.join(format!("profiles/{}", manifest.uuid));
+            let profile_path = profile_dir.join("profile.json");
+            let existing: Option<JsonValue> = if profile_path.exists() {
+                match parse_json(&fs::read_to_string(&profile_path)?, "profile") {
+                    Ok(v) => Some(v),
+                    Err(e) => return Err(LauncherProfileError::InvalidJson(e)),
+                }
+            } else {
+                None
+            };
+            let icon = match icon_img {
+                Some(icon_img) => {
+                    let path = profile_dir.join("icon.png");
+                    icon_img.save(&path)?;
+                    Some(path)
+                }
+                None => None,
+            };
+            // The Modrinth App only has a max memory setting, 'min_mem' is ignored
+            let mut profile = serde_json::json!({
+                "uuid": manifest.uuid,
+                "install_stage": "installed",
+                "path": manifest.uuid,
+                "metadata": {
+                    "name": manifest.name,
+                    "icon": icon,
+                    "game_version": manifest.loader.minecraft_version,
+                    "loader": manifest.loader.r#type,
+                    "loader_version": {
+                        "id": manifest.loader.version,
+                        "stable": true,
+                    },
+                    "groups": [],
+                    "linked_data": null,
+                    "date_created": now,
+                    "date_modified": now,
+                    "last_played": null,
+                },
+                "memory": max_mem.map(|v| serde_json::json!({ "maximum": v })),
+                "java": manifest.java_args.as_ref().map(|v| serde_json::json!({
+                    "extra_arguments": v.split_whitespace().collect::<Vec<_>>(),
+                })),
+                "projects": {},
+            });
+            if let Some(existing) = existing {
+                // Keep what the app tracks itself and the memory and java args the user tuned
+                let mut keep = vec!["projects"];
+                if !apply_jvm_settings {
+                    keep.extend(["memory", "java"]);
+                }
+                for key in keep {
+                    if let Some(v) = existing.get(key) {
+                        profile[key] = v.clone();
+                    }
+                }
+                for key in ["date_created", "groups", "last_played"] {
+                    if let Some(v) = existing.pointer(&format!("/metadata/{key}")) {
+                        profile["metadata"][key] = v.clone();
+                    }
+                }
+            }
+            fs::write(&profile_path, serde_json::to_string(&profile)?)?;
+        }
+    };
+    // A write which silently went wrong would otherwise only show up as a missing profile in the launcher
+    verify_launcher_profile(installer_profile.launcher.as_ref().unwrap(), &manifest.uuid, &modpack_root)?;
+    Ok(adjustments)
+}
+
+/// Reads back what 'create_launcher_profile' wrote and checks that the launcher can find and load the profile
+fn verify_launcher_profile(launcher: &Launcher, uuid: &str, modpack_root: &Path) -> Result<(), LauncherProfileError> {
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|e| LauncherProfileError::NotCreated(format!("Failed to read {path:#?}: {e}")))
+    };
+    match launcher {
+        Launcher::Vanilla(root) => {
+            let lp_obj: JsonValue = parse_json(
+                &read(&get_minecraft_folder_in(root).join("launcher_profiles.json"))?,
+                "launcher_profiles",
+            )
+            .map_err(LauncherProfileError::NotCreated)?;
+            let profile = lp_obj
+                .get("profiles")
+                .and_then(|x| x.get(uuid))
+                .ok_or_else(|| LauncherProfileError::NotCreated(format!("Missing profile '{uuid}'")))?;
+            let profile: LauncherProfile =
+                parse_json_value(profile.clone(), &format!("launcher_profiles.profiles.{uuid}"))
+                    .map_err(LauncherProfileError::NotCreated)?;
+            if profile.gameDir.as_deref().map(Path::new) != Some(modpack_root) {
+                return Err(LauncherProfileError::NotCreated(format!(
+                    "Profile '{uuid}' points to {:#?} instead of {modpack_root:#?}",
+                    profile.gameDir
+                )));
+            }
+        }
+        Launcher::MultiMC(root) => {
+            let instance = root.join(format!("instances/{uuid}"));
+            parse_json::<MMCPack>(&read(&instance.join("mmc-pack.json"))?, "mmc-pack")
+                .map_err(LauncherProfileError::NotCreated)?;
+            let instance_cfg = read(&instance.join("instance.cfg"))?;
+            // MultiMC skips instances without a type, the name is what's shown in the instance list
+            for key in ["InstanceType", "name"] {
+                if !instance_cfg
+                    .lines()
+                    .any(|x| x.split_once('=').is_some_and(|(k, v)| k == key && !v.is_empty()))
+                {
+                    return Err(LauncherProfileError::NotCreated(format!(
+                        "'instance.cfg' of '{uuid}' is missing '{key}'"
+                    )));
+                }
+            }
+        }
+        Launcher::Modrinth(root) => {
+            let profile: JsonValue =
+                parse_json(&read(&root.join(format!("profiles/{uuid}/profile.json")))?, "profile")
+                    .map_err(LauncherProfileError::NotCreated)?;
+            if profile.get("path").and_then(|x| x.as_str()) != Some(uuid) {
+                return Err(LauncherProfileError::NotCreated(format!(
+                    "'profile.json' of '{uuid}' doesn't point to the modpack"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Sets 'key=value' lines of a MultiMC 'instance.cfg', keys which don't exist yet are appended
+fn set_cfg_values(contents: &str, values: &[(&str, String)]) -> String {
+    let mut lines: Vec<String> = contents.lines().map(String::from).collect();
+    for (key, value) in values {
+        let line = format!("{key}={value}");
+        match lines
+            .iter_mut()
+            .find(|x| x.split_once('=').is_some_and(|(k, _)| k == *key))
+        {
+            Some(existing) => *existing = line,
+            None => lines.push(line),
+        }
+    }
+    lines.join("\n")
+}
+
+/// Returns 'item' if its path is located in 'modpack_root' without escaping it using '..'
+fn validate_item_path<T: Downloadable>(item: T, modpack_root: &Path) -> Result<T, DownloadError> {
+    if let Some(path) = item.get_path() {
+        // Items may be nested arbitrarily deep, e.g. in '<type dir>/<subfolder>/'
+        if !path.starts_with(modpack_root)
+            || path == modpack_root
+            || path
+                .components()
+                .any(|x| matches!(x, std::path::Component::ParentDir))
+        {
+            return Err(DownloadError::InvalidPath(item.get_name().to_string()));
+        }
+    }
+    Ok(item)
+}
+
+/// Returns the paths of the local manifests of all installed modpacks
+fn get_installed_manifest_paths(launcher: &Launcher) -> Result<Vec<PathBuf>, std::io::Error> {
+    Ok(match launcher {
+        Launcher::Vanilla(root) => {
+            fs::read_dir(root.join(".WC_OVHL/"))?.filter_map(|entry| {
+                let path = entry.ok()?.path().join("manifest.json");
+                if path.exists() {Some(path)} else {None}
+            }).collect()
+        },
+        Launcher::MultiMC(root) => {
+            fs::read_dir(root.join("instances/"))?.filter_map(|entry| {
+                let path = get_multimc_game_dir(&entry.ok()?.path()).join("manifest.json");
+                if path.exists() {Some(path)} else {None}
+            }).collect()
+        },
+        Launcher::Modrinth(root) => {
+            fs::read_dir(root.join("profiles/"))?.filter_map(|entry| {
+                let path = entry.ok()?.path().join("manifest.json");
+                if path.exists() {Some(path)} else {None}
+            }).collect()
+        },
+    })
+}
+
+fn get_installed_packs(launcher: &Launcher) -> Result<Vec<PackName>, std::io::Error> {
+    let mut packs = vec![];
+    for path in get_installed_manifest_paths(launcher)? {
+        // One broken modpack shouldn't hide the others
+        let contents = match fs::read_to_string(&path) {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("Skipping unreadable local manifest '{path:#?}': {e}");
+                continue;
+            }
+        };
+        let manifest: Result<Manifest, serde_json::Error> = serde_json::from_str(&contents);
+        if let Ok(manifest) = manifest {
+            packs.push(PackName {
+                name: manifest.subtitle,
+                uuid: manifest.uuid,
+                manifest_version: manifest.manifest_version,
+                modpack_version: manifest.modpack_version,
+            })
+        }
+    }
+    
+    Ok(packs)
+}
+
+/// Encodes the enabled features into a code which can be shared with other users, see 'decode_feature_code'
+fn encode_feature_code(features: &[String]) -> String {
+    engine::general_purpose::URL_SAFE_NO_PAD.encode(features.join(","))
+}
+
+/// Decodes a code made by 'encode_feature_code'. Ids which aren't features of 'manifest' (anymore) are ignored.
+fn decode_feature_code(code: &str, manifest: &Manifest) -> Result<Vec<String>, String> {
+    let decoded = match engine::general_purpose::URL_SAFE_NO_PAD.decode(code.trim()) {
+        Ok(v) => v,
+        Err(e) => return Err(format!("Invalid feature code: {e}")),
+    };
+    let decoded = match String::from_utf8(decoded) {
+        Ok(v) => v,
+        Err(e) => return Err(format!("Invalid feature code: {e}")),
+    };
+    Ok(decoded
+        .split(',')
+        .filter(|id| {
+            let exists = manifest.features.iter().any(|feat| feat.id == *id);
+            if !exists && !id.is_empty() && *id != default_id() {
+                warn!("Ignoring unknown feature '{id}' from feature code");
+            }
+            exists
+        })
+        .map(String::from)
+        .collect())
+}
+
+fn path_status(path: &Path) -> String {
+    format!(
+        "{} ({})",
+        path.display(),
+        if path.exists() { "exists" } else { "missing" }
+    )
+}
+
+/// Collects environment information used to triage installer issues.
+fn get_diagnostics(config: &Config) -> String {
+    let mut report = format!("Installer version: {}\n", env!("CARGO_PKG_VERSION"));
+    match PlatformInfo::new() {
+        Ok(info) => {
+            report += &format!(
+                "OS: {} {} {} ({})\n",
+                info.osname().to_string_lossy(),
+                info.release().to_string_lossy(),
+                info.version().to_string_lossy(),
+                info.machine().to_string_lossy()
+            )
+        }
+        Err(e) => report += &format!("OS: {} (failed to get platform info: {e})\n", env::consts::OS),
+    }
+    report += &format!("Configured launcher: {}\n", config.launcher);
+    if let Some(sandbox) = get_sandbox_root() {
+        report += &format!("Sandbox: {}\n", path_status(&sandbox));
+    }
+    let status = |path: Result<PathBuf, InstallError>| match path {
+        Ok(path) => path_status(&path),
+        Err(e) => format!("unknown ({e})"),
+    };
+    report += &format!("App data: {}\n", status(get_app_data()));
+    report += &format!("Config dir: {}\n", status(get_config_path()));
+    report += &format!(
+        "Config file: {}\n",
+        status(get_config_path().map(|x| x.join("config.json")))
+    );
+    report += &format!(
+        "Log file: {}\n",
+        status(get_config_path().map(|x| x.join("installer.log")))
+    );
+    report += &format!("Minecraft folder: {}\n", status(get_minecraft_folder()));
+    report += &format!(
+        "Launcher profiles: {}\n",
+        status(get_minecraft_folder().map(|x| x.join("launcher_profiles.json")))
+    );
+    for multimc in ["MultiMC", "PrismLauncher"] {
+        report += &format!(
+            "{multimc}: {}\n",
+            match get_multimc_folder(multimc) {
+                Ok(path) => path.display().to_string(),
+                Err(e) => format!("not found ({e})"),
+            }
+        );
+    }
+    report += &format!("Supported manifest version: {CURRENT_MANIFEST_VERSION}\n");
+    match get_launcher(&config.launcher) {
+        Ok(launcher) => match get_installed_packs(&launcher) {
+            Ok(packs) => {
+                report += &format!("Installed packs ({launcher}):\n");
+                for pack in packs {
+                    report += &format!(
+                        "\t{} ({}): version '{}', manifest version {}\n",
+                        pack.name, pack.uuid, pack.modpack_version, pack.manifest_version
+                    );
+                }
+            }
+            Err(e) => report += &format!("Installed packs: failed to read ({e})\n"),
+        },
+        Err(e) => report += &format!("Installed packs: invalid launcher ({e})\n"),
+    }
+    report
+}
+
+fn uninstall(launcher: &Launcher, uuid: &str) -> Result<(), std::io::Error> {
+    info!("Uninstalling modpack: '{uuid}'!");
+    let instance = match launcher {
+        Launcher::Vanilla(root) => {
+            root.join(format!(".WC_OVHL/{uuid}"))
+        }
+        Launcher::MultiMC(root) => {
+            get_multimc_game_dir(&root.join(format!("instances/{uuid}")))
+        }
+        Launcher::Modrinth(root) => {
+            root.join(format!("profiles/{uuid}"))
+        }
+    };
+    if instance.is_dir() {
+        // The user data folder is kept, see 'get_user_data_dir'
+        unlink_user_data(&instance);
+        fs::remove_dir_all(&instance)?;
+        info!("Removed: {instance:#?}");
+        fs::create_dir(instance)?;
+    } else {
+        error!("Failed to uninstall '{uuid}'");
+    }
+    let _ = isahc::post(
+        "https://tracking.commander07.workers.dev/track",
+        format!(
+            "{{
+        \"projectId\": \"55db8403a4f24f3aa5afd33fd1962888\",
+        \"dataSourceId\": \"{uuid}\",
+        \"userAction\": \"uninstall\",
+        \"additionalData\": {{}}
+    }}"));
+    info!("Uninstalled modpack!");
+    Ok(())
+}
+
+/// Downloads, enables or disables 'items' according to the enabled features.
+/// Returns the items with their paths and the names of the items skipped as they're no longer available.
+async fn download_helper<T: Downloadable + Debug, F: FnMut() -> () + Clone>(
+    items: Vec<T>,
+    installer_profile: &InstallerProfile,
+    modpack_root: &Path,
+    progress_callback: F
+) -> Result<(Vec<T>, Vec<String>), DownloadError> {
+    let enabled_features = &installer_profile.enabled_features;
+    let target_side = installer_profile.target_side.as_str();
+    let loader = &installer_profile.manifest.loader;
+    let http_client = &installer_profile.http_client;
+    let cancelled = &installer_profile.cancelled;
+    let file_system = &installer_profile.file_system;
+    let overrides = read_overrides(modpack_root, file_system.as_ref());
+    let progress = read_install_progress(modpack_root);
+    let existing = existing_paths(&items);
+    let skipped = Mutex::new(vec![]);
+    let results = futures::stream::iter(items.into_iter().map(|item| async {
+        let enabled = if overrides.disable.contains(item.get_name()) {
+            false
+        } else if overrides.enable.contains(item.get_name()) {
+            true
+        } else {
+            enabled_features.contains(item.get_id())
+                && (item.get_side() == "both" || item.get_side() == target_side)
+        };
+        // Items disabled in an earlier install are still there, so just rename them back
+        let item = match item.get_path().clone() {
+            Some(path) if enabled && is_disabled_path(&path) => {
+                let item = validate_item_path(item, modpack_root)?;
+                let enabled_path = path.with_extension("");
+                match file_system.rename(&path, &enabled_path) {
+                    Ok(_) => {
+                        info!("Enabled: '{enabled_path:#?}'");
+                        item.with_path(Some(enabled_path))
+                    }
+                    Err(e) => {
+                        warn!("Failed to enable '{path:#?}', downloading it again: {e}");
+                        item.with_path(None)
+                    }
+                }
+            }
+            _ => item,
+        };
+        if item.get_path().is_none() && enabled {
+            if cancelled.load(Ordering::Relaxed) {
+                return Err(DownloadError::Cancelled);
+            }
+            // Manifests rarely set 'side' for Modrinth items, their project knows which sides it supports
+            if item.get_side() == "both"
+                && item.get_source() == "modrinth"
+                && !modrinth_supports_side(&item, installer_profile.target_side, http_client).await
+            {
+                info!("Skipping '{}' as it doesn't support the {target_side} side", item.get_name());
+                return Ok(item);
+            }
+            if let Some(path) = resume_path(&progress, &item, modpack_root) {
+                info!("Resuming with already downloaded '{path:#?}'");
+                installer_profile.emit(InstallEvent::ItemDownloaded {
+                    name: item.get_name().clone(),
+                }).await;
+                (progress_callback.clone())();
+                return Ok(item.with_path(Some(path)));
+            }
+            let path = match item
+                .download(
+                    modpack_root,
+                    &loader.r#type,
+                    &loader.minecraft_version,
+                    http_client,
+                )
+                .await
+            {
+                Ok(v) => v,
+                // Removed items shouldn't prevent installing the rest, they're retried on the next update
+                Err(e @ DownloadError::NoLongerAvailable(_)) => {
+                    warn!("Skipping item: {e}");
+                    skipped.lock().unwrap().push(item.get_name().clone());
+                    installer_profile.emit(InstallEvent::ItemSkipped {
+                        name: item.get_name().clone(),
+                    }).await;
+                    (progress_callback.clone())();
+                    return Ok(item);
+                }
+                Err(e) => return Err(e),
+            };
+            record_install_progress(modpack_root, &item, &path);
+            installer_profile.emit(InstallEvent::ItemDownloaded {
+                name: item.get_name().clone(),
+            }).await;
+            (progress_callback.clone())();
+            Ok(item.with_path(Some(path)))
+        } else {
+            let item = validate_item_path(item, modpack_root)?;
+            let path = match item.get_path() {
+                // Kept as '<file>.disabled' so enabling it again doesn't require downloading it
+                Some(path) if !enabled && !is_disabled_path(path) => {
+                    let disabled_path = disabled_path(path);
+                    info!("Disabling: '{path:#?}'");
+                    match file_system.rename(path, &disabled_path) {
+                        Ok(_) => Some(disabled_path),
+                        Err(e) => {
+                            warn!("Failed to disable '{path:#?}', removing it instead: {e}");
+                            let _ = file_system.remove_file(path);
+                            None
+                        }
+                    }
+                }
+                path => path.to_owned(),
+            };
+            Ok(item.with_path(path))
+        }
+    }))
+    // Unlike 'buffer_unordered' this yields the results in manifest order so the local manifest is deterministic
+    .buffered(CONCURRENCY)
+    .collect::<Vec<Result<T, DownloadError>>>()
+    .await;
+    let mut return_vec = vec![];
+    let mut error = None;
+    for res in results {
+        match res {
+            Ok(v) => return_vec.push(v),
+            Err(DownloadError::Cancelled) => error = Some(DownloadError::Cancelled),
+            Err(e) => return Err(e),
+        }
+    }
+    if let Some(e) = error {
+        remove_new_files(&existing, &return_vec);
+        return Err(e);
+    }
+    Ok((return_vec, skipped.into_inner().unwrap()))
+}
+
+/// Item downloaded by an install which hasn't finished yet, see 'record_install_progress'
+#[derive(Debug, Deserialize, Serialize)]
+struct ProgressEntry {
+    name: String,
+    location: String,
+    version: String,
+    subfolder: Option<String>,
+    priority: Option<i32>,
+    path: PathBuf,
+    sha256: String,
+}
+
+/// Reads the items downloaded by an interrupted install, lines cut off by the interruption are skipped
+fn read_install_progress(modpack_root: &Path) -> Vec<ProgressEntry> {
+    match fs::read_to_string(modpack_root.join(INSTALL_PROGRESS)) {
+        Ok(v) => v.lines().filter_map(|x| serde_json::from_str(x).ok()).collect(),
+        Err(_) => vec![],
+    }
+}
+
+/// Appends the downloaded 'item' to the progress file so an interrupted install can resume without downloading it again.
+/// The local manifest is only written once the install finished, so it can't be used for this.
+fn record_install_progress<T: Downloadable>(modpack_root: &Path, item: &T, path: &Path) {
+    let sha256 = match fs::read(path) {
+        Ok(v) => sha256_hex(&v),
+        Err(e) => {
+            warn!("Failed to hash '{path:#?}' for the install progress: {e}");
+            return;
+        }
+    };
+    let entry = ProgressEntry {
+        name: item.get_name().clone(),
+        location: item.get_location().clone(),
+        version: item.get_version().clone(),
+        subfolder: item.get_subfolder().clone(),
+        priority: *item.get_priority(),
+        path: path.to_path_buf(),
+        sha256,
+    };
+    let res = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(modpack_root.join(INSTALL_PROGRESS))
+        .and_then(|mut file| writeln!(file, "{}", serde_json::to_string(&entry).unwrap()));
+    if let Err(e) = res {
+        warn!("Failed to record install progress: {e}");
+    }
+}
+
+/// Path of 'item' if an interrupted install already downloaded it and the file is still intact
+fn resume_path<T: Downloadable>(progress: &[ProgressEntry], item: &T, modpack_root: &Path) -> Option<PathBuf> {
+    let entry = progress.iter().rev().find(|x| {
+        &x.name == item.get_name()
+            && &x.location == item.get_location()
+            && &x.version == item.get_version()
+            && &x.subfolder == item.get_subfolder()
+            && &x.priority == item.get_priority()
+    })?;
+    if !entry.path.starts_with(modpack_root) {
+        return None;
+    }
+    let contents = fs::read(&entry.path).ok()?;
+    if sha256_hex(&contents) == entry.sha256 {
+        Some(entry.path.clone())
+    } else {
+        None
+    }
+}
+
+/// Local 'overrides.json' in the modpack root, lists item names which are disabled or enabled regardless of features
+#[derive(Debug, Deserialize, Default)]
+struct Overrides {
+    #[serde(default)]
+    disable: Vec<String>,
+    #[serde(default)]
+    enable: Vec<String>,
+}
+
+/// Reads the 'Overrides' of the modpack, an invalid file is ignored so it can't break installing
+fn read_overrides(modpack_root: &Path, file_system: &dyn FileSystem) -> Overrides {
+    let contents = match file_system.read(&modpack_root.join("overrides.json")) {
+        Ok(v) => v,
+        Err(_) => return Overrides::default(),
+    };
+    match parse_json(&String::from_utf8_lossy(&contents), "overrides") {
+        Ok(v) => {
+            info!("Using overrides: {v:#?}");
+            v
+        }
+        Err(e) => {
+            warn!("Ignoring invalid 'overrides.json': {e}");
+            Overrides::default()
+        }
+    }
+}
+
+/// Minecraft and the loaders ignore files ending in '.disabled'
+fn disabled_path(path: &Path) -> PathBuf {
+    let mut filename = path.file_name().unwrap_or_default().to_os_string();
+    filename.push(".disabled");
+    path.with_file_name(filename)
+}
+
+fn is_disabled_path(path: &Path) -> bool {
+    path.extension().is_some_and(|x| x == "disabled")
+}
+
+fn existing_paths<T: Downloadable>(items: &[T]) -> Vec<PathBuf> {
+    items.iter().filter_map(|x| x.get_path().clone()).collect()
+}
+
+/// Removes files of items which were downloaded during this install. Used to clean up after a cancelled install.
+fn remove_new_files<T: Downloadable>(existing: &[PathBuf], downloaded: &[T]) {
+    for item in downloaded {
+        if let Some(path) = item.get_path() {
+            if !existing.contains(path) {
+                info!("Removing: '{path:#?}'");
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+}
+
+/// Returns the 'managed_files' of the include stored as 'name' in 'Manifest::included_files'.
+/// Includes are keyed by their zip name ('<id>.zip'), remote includes by their location.
+fn include_managed_files<'a>(manifest: &'a Manifest, name: &str) -> &'a [String] {
+    manifest
+        .include
+        .iter()
+        .find(|x| format!("{}.zip", x.id) == name)
+        .and_then(|x| x.managed_files.as_deref())
+        .or_else(|| {
+            manifest
+                .remote_include
+                .iter()
+                .flatten()
+                .find(|x| x.location == name)
+                .and_then(|x| x.managed_files.as_deref())
+        })
+        .unwrap_or_default()
+}
+
+/// Converts a glob pattern into a regex matching paths relative to the modpack root with '/' as separator.
+/// '*' and '?' don't match '/', '**' matches across directories.
+fn glob_to_regex(pattern: &str) -> Regex {
+    let mut re = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                // 'config/**/x' also matches 'config/x'
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    re += "(?:.*/)?";
+                } else {
+                    re += ".*";
+                }
+            }
+            '*' => re += "[^/]*",
+            '?' => re += "[^/]",
+            c => re += &regex::escape(&c.to_string()),
+        }
+    }
+    re.push('$');
+    Regex::new(&re).unwrap() // everything but the wildcards is escaped
+}
+
+/// Returns why 'pattern' can't be used in 'managed_files', 'None' if it can
+fn managed_files_pattern_problem(pattern: &str) -> Option<&'static str> {
+    let parts: Vec<&str> = pattern.split(['/', '\\']).collect();
+    if pattern.is_empty() || pattern.starts_with(['/', '\\']) || pattern.contains(':') || parts.contains(&"..") {
+        return Some("isn't relative to the modpack root");
+    }
+    if parts[0].contains(['*', '?']) {
+        return Some("matches files anywhere in the modpack root");
+    }
+    if USER_DATA_DIRS.contains(&parts[0]) {
+        return Some("matches user data");
+    }
+    if MANAGED_DIRS.contains(&parts[0]) && parts[1..].iter().all(|x| x.chars().all(|c| c == '*')) {
+        return Some("matches a whole item folder");
+    }
+    None
+}
+
+/// Paths of the items of 'manifest', 'remove_managed_files' never removes these
+fn manifest_item_paths(manifest: &Manifest) -> Vec<PathBuf> {
+    [
+        existing_paths(&manifest.mods),
+        existing_paths(&manifest.shaderpacks),
+        existing_paths(&manifest.resourcepacks),
+    ]
+    .concat()
+}
+
+/// Removes every file in 'modpack_root' matching one of 'patterns', see 'Include::managed_files'.
+/// Symlinked directories aren't followed so nothing outside of the modpack root is touched. 'item_paths', the local
+/// manifest and user data are kept even if a pattern matches them.
+fn remove_managed_files(modpack_root: &Path, patterns: &[String], item_paths: &HashSet<PathBuf>) {
+    fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+        let entries = match fs::read_dir(dir) {
+            Ok(v) => v,
+            Err(_) => return,
+        };
+        for entry in entries.flatten() {
+            match entry.file_type() {
+                Ok(x) if x.is_dir() => collect_files(&entry.path(), files),
+                Ok(_) => files.push(entry.path()),
+                Err(_) => {}
+            }
+        }
+    }
+    for pattern in patterns {
+        // Local manifests installed before patterns were validated may still have them
+        if let Some(problem) = managed_files_pattern_problem(pattern) {
+            warn!("Ignoring 'managed_files' pattern '{pattern}' which {problem}");
+            continue;
+        }
+        let re = glob_to_regex(pattern);
+        // Only the directories before the first wildcard have to be searched
+        let parts = pattern.split('/').collect::<Vec<_>>();
+        let base = parts[..parts.len() - 1]
+            .iter()
+            .take_while(|x| !x.contains(['*', '?']))
+            .fold(modpack_root.to_path_buf(), |path, x| path.join(x));
+        let mut files = vec![];
+        collect_files(&base, &mut files);
+        for file in files {
+            let relative = file
+                .strip_prefix(modpack_root)
+                .unwrap() // 'file' is always inside of 'modpack_root'
+                .components()
+                .map(|x| x.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let protected = item_paths.contains(&file)
+                || relative == "manifest.json"
+                || relative == INSTALL_PROGRESS
+                || USER_DATA_DIRS.contains(&relative.split('/').next().unwrap_or_default());
+            if re.is_match(&relative) && !protected {
+                info!("Removing: '{file:#?}'");
+                let _ = fs::remove_file(file);
+            }
+        }
+    }
+}
+
+/// Verifies every include (see 'Included::verify') on its own thread.
+/// Hashing is blocking so this keeps the async runtime (and with it the GUI) responsive while includes are hashed in parallel.
+async fn verify_includes(includes: &HashMap<String, Included>) -> HashMap<String, Option<Included>> {
+    let verifications = includes.iter().map(|(name, included)| {
+        let (sender, receiver) = oneshot::channel();
+        let included = included.clone();
+        std::thread::spawn(move || {
+            let _ = sender.send(included.verify());
+        });
+        async move { (name.clone(), receiver.await.unwrap_or(None)) }
+    });
+    futures::future::join_all(verifications)
+        .await
+        .into_iter()
+        .collect()
+}
+
+/// Fetches the GitHub release of the modpack branch which holds the include assets and their hashes
+async fn fetch_include_release(
+    installer_profile: &InstallerProfile,
+) -> Result<(GithubRelease, HashMap<String, String>), InstallError> {
+    let http_client = &installer_profile.http_client;
+    let release_url = http_client.endpoints.github_api.clone()
+        + installer_profile.modpack_source.as_str()
+        + "releases/tags/"
+        + installer_profile.modpack_branch.as_str();
+    let mut release_resp = match http_client.get_async(release_url.clone()).await {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Network(format!("Failed to retrieve releases: {e}"))),
+    };
+    // Already downloaded items are kept so retrying once the release exists doesn't download them again
+    match release_resp.status() {
+        StatusCode::OK => (),
+        status => {
+            // Error responses are cached as well, drop it so a retry sees a newly created release
+            http_client.invalidate(release_url).await;
+            return Err(if status == StatusCode::NOT_FOUND {
+                InstallError::Other(format!(
+                    "No release found for branch '{}' but the manifest declares includes",
+                    installer_profile.modpack_branch
+                ))
+            } else {
+                InstallError::Network(format!(
+                    "Encountered '{}' error code when retrieving the release for branch '{}'",
+                    status.as_u16(),
+                    installer_profile.modpack_branch
+                ))
+            });
+        }
+    }
+    let release: GithubRelease = match parse_json(
+        &release_resp.text().await.unwrap_or_default(),
+        "release",
+    ) {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Parse(format!("Failed to parse release: {e}"))),
+    };
+    let hash_pairs: HashMap<String, String> = match release.body.as_deref().map(|x| parse_json(x, "release.body")) {
+        Some(Ok(v)) => v,
+        Some(Err(e)) => {
+            return Err(InstallError::Parse(format!("Failed to parse include hashes: {e}")))
+        }
+        None => {
+            return Err(InstallError::Parse(String::from(
+                "Missing body with include hashes on modpack release",
+            )))
+        }
+    };
+    Ok((release, hash_pairs))
+}
+
+/// Downloads the zip at 'url' and extracts it into 'path', returns the extracted files.
+/// If 'sha256' is given the download is checked against it before anything is extracted.
+async fn download_zip(
+    name: &str,
+    http_client: &CachedHttpClient,
+    url: &str,
+    path: &Path,
+    sha256: Option<&str>,
+) -> Result<Vec<String>, DownloadError> {
+    info!("Downloading '{}'", name);
+    let mut files: Vec<String> = vec![];
+    // download and unzip in modpack root
+    let mut tries = 0;
+    let mut content_resp = match loop {
+        let content_resp = http_client
+            .with_headers(
+                url,
+                &[("Accept", "application/octet-stream")],
+            )
+            .await;
+        if content_resp.is_err() {
+            tries += 1;
+            if tries >= ATTEMPTS {
+                break Err(content_resp.err().unwrap());
+            }
+        } else {
+            break Ok(content_resp.unwrap());
+        }
+    } {
+        Ok(v) => v,
+        Err(e) => return Err(DownloadError::HttpError(name.to_string(), e)),
+    };
+    let content_byte_resp = match content_resp.bytes().await {
+        Ok(v) => v,
+        Err(e) => return Err(DownloadError::IoError(name.to_string(), e)),
+    };
+    if sha256.is_some_and(|x| !x.eq_ignore_ascii_case(&sha256_hex(&content_byte_resp))) {
+        return Err(DownloadError::HashMismatch(name.to_string()));
+    }
+    fs::create_dir_all(path).expect("Failed to create unzip path");
+    let zipfile_path = path.join("tmp_include.zip");
+    fs::write(&zipfile_path, content_byte_resp)
+        .expect("Failed to write 'tmp_include.zip'!");
+    info!("Downloaded '{}'", name);
+    info!("Unzipping '{}'", name);
+    let zipfile = fs::File::open(&zipfile_path).unwrap();
+    let mut archive = zip::ZipArchive::new(zipfile).unwrap();
+    // modified from https://github.com/zip-rs/zip/blob/e32db515a2a4c7d04b0bf5851912a399a4cbff68/examples/extract.rs#L19
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).unwrap();
+        let outpath = match file.enclosed_name() {
+            Some(outpath) => path.join(outpath),
+            None => continue,
+        };
+        if (*file.name()).ends_with('/') {
+            fs::create_dir_all(&outpath).unwrap();
+        } else {
+            if let Some(p) = outpath.parent() {
+                if !p.exists() {
+                    fs::create_dir_all(p).unwrap();
+                }
+            }
+            let tmp = part_path(&outpath);
+            let mut outfile = fs::File::create(&tmp).unwrap();
+            std::io::copy(&mut file, &mut outfile).unwrap();
+            // Has to be closed before renaming it on Windows
+            drop(outfile);
+            fs::rename(&tmp, &outpath).unwrap();
+            files.push(outpath.to_str().unwrap().to_string());
+        }
+    }
+    fs::remove_file(&zipfile_path).expect("Failed to remove tmp 'tmp_include.zip'!");
+    info!("Unzipped '{}'", name);
+    Ok(files)
+}
+
+/// Downloads and decodes the modpack icon, returning 'None' so the install continues with the default icon if anything fails
+async fn download_icon(installer_profile: &InstallerProfile) -> Option<DynamicImage> {
+    let url = installer_profile.http_client.endpoints.github_raw.clone()
+        + installer_profile.modpack_source.as_str()
+        + installer_profile.modpack_branch.as_str()
+        + "/"
+        + installer_profile
+            .manifest
+            .icon_file
+            .as_deref()
+            .unwrap_or("icon.png");
+    let mut resp = match installer_profile.http_client.get_async(url.clone()).await {
+        Ok(v) => v,
+        Err(e) => {
+            warn!("Failed to download icon '{url}', using the default icon: {e}");
+            return None;
+        }
+    };
+    if resp.status() != StatusCode::OK {
+        warn!(
+            "Encountered '{}' error code when downloading icon '{url}', using the default icon",
+            resp.status().as_u16()
+        );
+        return None;
+    }
+    // Trust the content type if the server sent a known image type, otherwise guess from the contents
+    let format = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| ImageFormat::from_mime_type(v.split(';').next().unwrap_or_default().trim()));
+    let bytes = match resp.bytes().await {
+        Ok(v) => v,
+        Err(e) => {
+            warn!("Failed to read icon '{url}', using the default icon: {e}");
+            return None;
+        }
+    };
+    let reader = match format {
+        Some(format) => ImageReader::with_format(Cursor::new(bytes), format),
+        None => match ImageReader::new(Cursor::new(bytes)).with_guessed_format() {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("Could not guess format of icon '{url}', using the default icon: {e}");
+                return None;
+            }
+        },
+    };
+    match reader.decode() {
+        Ok(v) => Some(v),
+        Err(e) => {
+            warn!("Failed to decode icon '{url}', using the default icon: {e}");
+            None
+        }
+    }
+}
+
+pub async fn install<F: FnMut() -> () + Clone>(
+    installer_profile: &InstallerProfile,
+    side: Side,
+    progress_callback: F,
+) -> Result<InstallReport, InstallError> {
+    let mut side_profile = installer_profile.clone();
+    side_profile.target_side = side;
+    let installer_profile = &side_profile;
+    installer_profile.emit(InstallEvent::Started {
+        total: count_install_items(installer_profile),
+    }).await;
+    let res = install_inner(installer_profile, progress_callback).await;
+    // Updates reuse 'install', only fresh installs and reinstalls are counted
+    if res.is_ok() && !installer_profile.installed {
+        ping_install_count(installer_profile);
+    }
+    match &res {
+        Ok(_) => installer_profile.emit(InstallEvent::Finished).await,
+        Err(e) => installer_profile.emit(InstallEvent::Failed { error: e.to_string() }).await,
+    }
+    res
+}
+
+/// Tells the manifest's 'install_count_endpoint' that the modpack was installed if the user opted in to it.
+/// Only the uuid and version of the modpack are sent. The request runs on its own thread so it never delays or fails the install.
+fn ping_install_count(installer_profile: &InstallerProfile) {
+    let endpoint = match &installer_profile.manifest.install_count_endpoint {
+        Some(v) => v.clone(),
+        None => return,
+    };
+    if !read_config()
+        .and_then(|config| config.install_counting)
+        .unwrap_or(false)
+    {
+        return;
+    }
+    let body = serde_json::json!({
+        "uuid": installer_profile.manifest.uuid,
+        "version": installer_profile.manifest.modpack_version,
+    })
+    .to_string();
+    std::thread::spawn(move || {
+        let request = match Request::post(&endpoint)
+            .header("Content-Type", "application/json")
+            .timeout(INSTALL_COUNT_TIMEOUT)
+            .body(body)
+        {
+            Ok(v) => v,
+            Err(e) => {
+                warn!("Invalid install count endpoint '{endpoint}': {e}");
+                return;
+            }
+        };
+        match build_http_client().send(request) {
+            Ok(v) if v.status().is_success() => info!("Counted install at '{endpoint}'"),
+            Ok(v) => warn!(
+                "Encountered '{}' error code when counting install at '{endpoint}'",
+                v.status().as_u16()
+            ),
+            Err(e) => warn!("Failed to count install at '{endpoint}': {e}"),
+        }
+    });
+}
+
+/// Amount of 'items' without a path which are enabled for 'installer_profile', i.e. the ones that get downloaded
+fn count_pending_items<T: Downloadable>(items: &[T], installer_profile: &InstallerProfile) -> usize {
+    items
+        .iter()
+        .filter(|item| {
+            item.get_path().is_none()
+                && installer_profile.enabled_features.contains(item.get_id())
+                && (item.get_side() == "both" || item.get_side() == installer_profile.target_side.as_str())
+        })
+        .count()
+}
+
+/// Amount of items and includes 'install' will download, used for 'InstallEvent::Started'
+fn count_install_items(installer_profile: &InstallerProfile) -> usize {
+    let manifest = &installer_profile.manifest;
+    count_pending_items(&manifest.mods, installer_profile)
+        + count_pending_items(&manifest.shaderpacks, installer_profile)
+        + count_pending_items(&manifest.resourcepacks, installer_profile)
+        + manifest
+            .include
+            .iter()
+            .filter(|inc| installer_profile.enabled_features.contains(&inc.id))
+            .count()
+        + manifest
+            .remote_include
+            .iter()
+            .flatten()
+            .filter(|inc| installer_profile.enabled_features.contains(&inc.id))
+            .count()
+}
+
+/// Clears the download state of a previous install and applies the shared store setting
+fn reset_http_client(installer_profile: &InstallerProfile) {
+    let http_client = &installer_profile.http_client;
+    http_client.downloads.lock().unwrap().clear();
+    http_client.claimed_paths.lock().unwrap().clear();
+    *http_client.store.lock().unwrap() = if use_shared_store() {
+        Some(get_store_root(installer_profile.launcher.as_ref().unwrap()))
+    } else {
+        None
+    };
+}
+
+/// Claims the paths of items that keep their installed file so new items can't be written over them.
+/// Disabled items claim their enabled path as they are renamed back to it once re-enabled.
+fn claim_installed_paths(http_client: &CachedHttpClient, manifest: &Manifest) {
+    let installed = [
+        existing_paths(&manifest.mods),
+        existing_paths(&manifest.shaderpacks),
+        existing_paths(&manifest.resourcepacks),
+    ]
+    .concat();
+    http_client
+        .claimed_paths
+        .lock()
+        .unwrap()
+        .extend(installed.into_iter().map(|x| {
+            if is_disabled_path(&x) {
+                x.with_extension("")
+            } else {
+                x
+            }
+        }));
+}
+
+async fn install_inner<F: FnMut() + Clone>(
+    installer_profile: &InstallerProfile,
+    mut progress_callback: F,
+) -> Result<InstallReport, InstallError> {
+    info!("Installing modpack");
+    info!("installer_profile = {installer_profile:#?}");
+    installer_profile.selected_launcher()?;
+    // Resolved before anything else so the concrete version ends up in the profile, loader folder and local manifest
+    let resolved_profile;
+    let installer_profile = if installer_profile.manifest.loader.version == LATEST_LOADER {
+        let mut profile = installer_profile.clone();
+        profile.manifest.loader = resolve_loader(installer_profile).await?;
+        resolved_profile = profile;
+        &resolved_profile
+    } else {
+        installer_profile
+    };
+    let modpack_root = &get_modpack_root(
+        installer_profile.selected_launcher()?,
+        &installer_profile.manifest.uuid,
+    );
+    let manifest = &installer_profile.manifest;
+    let http_client = &installer_profile.http_client;
+    let file_system = installer_profile.file_system.as_ref();
+    reset_http_client(installer_profile);
+    claim_installed_paths(http_client, manifest);
+    // MultiMC and the Modrinth App install the loader themselves
+    let minecraft_folder = match installer_profile.launcher.as_ref().unwrap() {
+        Launcher::Vanilla(root) => Some(get_minecraft_folder_in(root)),
+        Launcher::MultiMC(_) | Launcher::Modrinth(_) => None,
+    };
+    // Updates re-check the loader json as it may have changed upstream since it was first downloaded
+    let check_loader = installer_profile.local_manifest.as_ref().is_some_and(|x| {
+        x.modpack_version != manifest.modpack_version || x.loader != manifest.loader
+    });
+    let loader_future = minecraft_folder.as_ref().map(|minecraft_folder| {
+        manifest.loader.download(
+            minecraft_folder,
+            &manifest.loader.r#type,
+            http_client,
+            check_loader,
+            installer_profile.force_loader,
+        )
+    });
+    let (mods_w_path, skipped_mods) = match download_helper(
+        manifest.mods.clone(),
+        installer_profile,
+        modpack_root.as_path(),
+        progress_callback.clone()
+    )
+    .await
+    {
+        Ok(v) => v,
+        Err(e) => return Err(e.into()),
+    };
+    let (shaderpacks_w_path, skipped_shaderpacks) = match download_helper(
+        manifest.shaderpacks.clone(),
+        installer_profile,
+        modpack_root.as_path(),
+        progress_callback.clone()
+    )
+    .await
+    {
+        Ok(v) => v,
+        Err(e) => {
+            if let DownloadError::Cancelled = e {
+                remove_new_files(&existing_paths(&manifest.mods), &mods_w_path);
+            }
+            return Err(e.into());
+        }
+    };
+    let (resourcepacks_w_path, skipped_resourcepacks) = match download_helper(
+        manifest.resourcepacks.clone(),
+        installer_profile,
+        modpack_root.as_path(),
+        progress_callback.clone()
+    )
+    .await
+    {
+        Ok(v) => v,
+        Err(e) => {
+            if let DownloadError::Cancelled = e {
+                remove_new_files(&existing_paths(&manifest.mods), &mods_w_path);
+                remove_new_files(&existing_paths(&manifest.shaderpacks), &shaderpacks_w_path);
+            }
+            return Err(e.into());
+        }
+    };
+    let cleanup_cancelled = |new_include_files: &[String]| {
+        remove_new_files(&existing_paths(&manifest.mods), &mods_w_path);
+        remove_new_files(&existing_paths(&manifest.shaderpacks), &shaderpacks_w_path);
+        remove_new_files(&existing_paths(&manifest.resourcepacks), &resourcepacks_w_path);
+        for file in new_include_files {
+            info!("Removing: '{file}'");
+            let _ = fs::remove_file(file);
+        }
+        Err(InstallError::Cancelled)
+    };
+    let mut new_include_files: Vec<String> = vec![];
+    let mut included_files: HashMap<String, Included> = HashMap::new();
+    let inc_files = match installer_profile.local_manifest.clone() {
+        Some(local_manifest) => match local_manifest.included_files {
+            Some(files) => files,
+            None => HashMap::new(),
+        },
+        None => HashMap::new(),
+    };
+    // Managed files of includes must never take the files of (old or new) items with them
+    let item_paths: HashSet<PathBuf> = [
+        existing_paths(&mods_w_path),
+        existing_paths(&shaderpacks_w_path),
+        existing_paths(&resourcepacks_w_path),
+    ]
+    .concat()
+    .into_iter()
+    .chain(installer_profile.local_manifest.iter().flat_map(manifest_item_paths))
+    .collect();
+    // The installed manifest knows which files the installed version of an include manages
+    let managed_files = |name: &str| match &installer_profile.local_manifest {
+        Some(local_manifest) => include_managed_files(local_manifest, name).to_vec(),
+        None => vec![],
+    };
+    for inc in &inc_files {
+        if !installer_profile
+            .enabled_features
+            .contains(&inc.0.replace(".zip", ""))
+        {
+            for file in &inc.1.files {
+                info!("Removing: '{file}'");
+                let _ = file_system.remove_file(Path::new(file));
+            }
+            remove_managed_files(modpack_root, &managed_files(inc.0), &item_paths);
+        }
+    }
+    // Removed includes fail verification at the first missing file so they are cheap to check
+    let verified_includes = verify_includes(&inc_files).await;
+    if !manifest.include.is_empty() {
+        // Includes with a 'url' don't need the release, it only has to exist if one of them has none
+        let release = if manifest
+            .include
+            .iter()
+            .any(|x| x.url.is_none() && installer_profile.enabled_features.contains(&x.id))
+        {
+            Some(fetch_include_release(installer_profile).await?)
+        } else {
+            None
+        };
+        // Returns the installed files of an include if they're still valid for 'version', otherwise removes them
+        let reuse_include = |inc_zip_name: &str, version: &str| -> Result<Option<Included>, InstallError> {
+            let local_inc = match inc_files.get(inc_zip_name) {
+                Some(v) => v,
+                None => return Ok(None),
+            };
+            if let Some(verified) = Some(local_inc)
+                .filter(|v| v.md5 == version)
+                .and_then(|_| verified_includes.get(inc_zip_name).cloned().flatten())
+            {
+                return Ok(Some(verified));
+            }
+            for file in &local_inc.files {
+                let path = Path::new(file);
+                if !path.starts_with(modpack_root) {
+                    return Err(InstallError::InvalidManifest(format!(
+                        "Local include path '{file}' was not located in modpack root!"
+                    )));
+                }
+                let _ = file_system.remove_file(path);
+            }
+            remove_managed_files(modpack_root, &managed_files(inc_zip_name), &item_paths);
+            Ok(None)
+        };
+        let mut downloaded_assets = vec![];
+        for inc in &manifest.include {
+            if !installer_profile.enabled_features.contains(&inc.id) {
+                continue;
+            }
+            if installer_profile.cancelled.load(Ordering::Relaxed) {
+                return cleanup_cancelled(&new_include_files);
+            }
+            let inc_zip_name = inc.id.clone() + ".zip";
+            if let Some(url) = &inc.url {
+                // Without a 'hash' the download fails verification, 'validate_manifest' warns about it
+                let hash = inc.hash.clone().unwrap_or_default();
+                if let Some(verified) = reuse_include(&inc_zip_name, &hash)? {
+                    included_files.insert(inc_zip_name, verified);
+                    info!("Skipping '{}' as it is already downloaded", inc.location);
+                    continue;
+                }
+                let files = match download_zip(&inc_zip_name, http_client, url, modpack_root, Some(&hash)).await {
+                    Ok(v) => v,
+                    Err(e) => return Err(e.into()),
+                };
+                new_include_files.extend(files.iter().cloned());
+                included_files.insert(inc_zip_name, Included::new(hash, files));
+                info!("'{}' is now installed", inc.location);
+                installer_profile.emit(InstallEvent::IncludeExtracted { id: inc.id.clone() }).await;
+                progress_callback();
+                continue;
+            }
+            let (release, hash_pairs) = release.as_ref().unwrap(); // fetched as this include has no 'url'
+            for asset in &release.assets {
+                if asset.name == inc_zip_name && !downloaded_assets.contains(&asset.id) {
+                    let md5 = match hash_pairs.get(&inc_zip_name) {
+                        Some(v) => v.to_owned(),
+                        None => {
+                            return Err(InstallError::InvalidManifest(format!(
+                                "Release '{}' has no hash for '{inc_zip_name}' in its body",
+                                installer_profile.modpack_branch
+                            )))
+                        }
+                    };
+                    if let Some(verified) = reuse_include(&inc_zip_name, &md5)? {
+                        included_files.insert(inc_zip_name, verified);
+                        info!("Skipping '{}' as it is already downloaded", asset.name);
+                        break;
+                    }
+                    let files = match download_zip(&asset.name, http_client, &format!(
+                        "{}{}releases/assets/{}",
+                        http_client.endpoints.github_api, installer_profile.modpack_source, asset.id
+                    ), modpack_root, None).await {
+                        Ok(v) => v,
+                        Err(e) => return Err(e.into()),
+                    };
+                    new_include_files.extend(files.iter().cloned());
+                    included_files.insert(inc_zip_name.clone(), Included::new(md5, files));
+                    info!("'{}' is now installed", asset.name);
+                    installer_profile.emit(InstallEvent::IncludeExtracted { id: inc.id.clone() }).await;
+                    progress_callback();
+                    downloaded_assets.push(asset.id);
+                    break;
+                }
+            }
+        }
+
+        if let Some(includes) = manifest.remote_include.clone() {
+            for include in includes {
+                if !installer_profile.enabled_features.contains(&include.id) {
+                    continue;
+                }
+                if installer_profile.cancelled.load(Ordering::Relaxed) {
+                    return cleanup_cancelled(&new_include_files);
+                }
+                let name = include.name.unwrap_or(include.location.clone());
+                let outpath = if let Some(path) = include.path {
+                    modpack_root.join(path)
+                } else {
+                    modpack_root.to_owned()
+                };
+                match inc_files.get(&include.location) {
+                    Some(local_inc) => {
+                        if let Some(verified) = Some(local_inc)
+                            .filter(|v| v.md5 == include.version)
+                            .and_then(|_| verified_includes.get(&include.location).cloned().flatten())
+                        {
+                            included_files.insert(include.location, verified);
+                            info!("Skipping '{}' as it is already downloaded", name);
+                            continue;
+                        } else {
+                            for file in &local_inc.files {
+                                let path = Path::new(file);
+                                if !path.starts_with(&outpath) {
+                                    return Err(InstallError::InvalidManifest(format!(
+                                        "Local include path '{file}' was not located in modpack root!"
+                                    )));
+                                }
+                                let _ = file_system.remove_file(path);
+                            }
+                            remove_managed_files(modpack_root, &managed_files(&include.location), &item_paths);
+                        }
+                    }
+                    None => (),
+                };
+                let files = match download_zip(&name, http_client, &include.location, &outpath, None).await {
+                    Ok(v) => v,
+                    Err(e) => return Err(e.into()),
+                };
+                new_include_files.extend(files.iter().cloned());
+                included_files.insert(name.clone(), Included::new(include.version, files));
+                info!("'{}' is now installed", name);
+                installer_profile.emit(InstallEvent::IncludeExtracted { id: include.id.clone() }).await;
+                progress_callback();
+            }
+        }
+    }
+    // After the includes as those may ship their own 'iris.properties'
+    if manifest.activate_shaderpack.unwrap_or(false) {
+        activate_shaderpack(&shaderpacks_w_path, modpack_root);
+    }
+    // Only added when it's new so a server the player removed doesn't come back on every update
+    let server_changed = installer_profile
+        .local_manifest
+        .as_ref()
+        .is_none_or(|x| x.default_server != manifest.default_server);
+    if let Some(server) = manifest.default_server.as_ref().filter(|_| server_changed) {
+        if let Err(e) = add_default_server(server, modpack_root) {
+            warn!("Failed to add server '{}' to 'servers.dat': {e}", server.address);
+        }
+    }
+    let mods_w_path = fill_modrinth_authors(mods_w_path, http_client).await;
+    let shaderpacks_w_path = fill_modrinth_authors(shaderpacks_w_path, http_client).await;
+    let resourcepacks_w_path = fill_modrinth_authors(resourcepacks_w_path, http_client).await;
+    let local_manifest = Manifest {
+        mods: mods_w_path,
+        shaderpacks: shaderpacks_w_path,
+        resourcepacks: resourcepacks_w_path,
+        enabled_features: installer_profile.enabled_features.clone(),
+        included_files: Some(included_files),
+        source: Some(format!(
+            "{}{}",
+            installer_profile.modpack_source, installer_profile.modpack_branch
+        )),
+        // Only informational, left out if the path of the installer can't be determined
+        installer_path: env::current_exe()
+            .and_then(|x| x.canonicalize())
+            .ok()
+            .and_then(|x| x.to_str().map(|x| x.replace("\\\\?\\", ""))),
+        ..manifest.clone()
+    };
+    let local_manifest_json = match serde_json::to_string(&local_manifest) {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Parse(format!("Failed to serialize 'manifest.json': {e}"))),
+    };
+    if let Err(e) = write_atomic(&modpack_root.join(Path::new("manifest.json")), local_manifest_json) {
+        return Err(InstallError::Io(format!("Failed to save a local copy of 'manifest.json': {e}")));
+    }
+    // Everything is in the local manifest now
+    let _ = fs::remove_file(modpack_root.join(INSTALL_PROGRESS));
+    let icon_img = if manifest.icon {
+        download_icon(installer_profile).await
+    } else {
+        None
+    };
+    let memory_adjustments = match create_launcher_profile(&installer_profile, icon_img) {
+        Ok(v) => v,
+        Err(e) => return Err(e.into()),
+    };
+    separate_user_data(installer_profile, modpack_root);
+    if let Some(loader_future) = loader_future {
+        match loader_future.await {
+            Ok(LoaderStatus::Present) => info!("Loader is already installed"),
+            Ok(LoaderStatus::Downloaded(path)) => info!("Installed loader into '{path:#?}'"),
+            Err(e) => return Err(e.into()),
+        }
+    }
+    info!("Installed modpack!");
+    Ok(InstallReport {
+        skipped: [skipped_mods, skipped_shaderpacks, skipped_resourcepacks].concat(),
+        memory_adjustments,
+    })
+}
+
+/// Moves the user data out of 'modpack_root' if 'separate_user_data' is enabled in the settings, see 'link_user_data'
+fn separate_user_data(installer_profile: &InstallerProfile, modpack_root: &Path) {
+    if !read_config()
+        .and_then(|config| config.separate_user_data)
+        .unwrap_or(false)
+    {
+        return;
+    }
+    let user_data_dir = get_user_data_dir(
+        installer_profile.launcher.as_ref().unwrap(), // only called once the modpack is installed
+        &installer_profile.manifest.uuid,
+    );
+    // The game still works with its user data in the modpack root, so this doesn't fail the install
+    if let Err(e) = link_user_data(modpack_root, &user_data_dir) {
+        warn!("Failed to move user data to {user_data_dir:#?}: {e}");
+    }
+}
+
+/// Selects the only enabled shaderpack in 'config/iris.properties' so it's active on first launch.
+/// A selection the user made themselves is kept as long as that shaderpack still exists.
+fn activate_shaderpack(shaderpacks: &[Shaderpack], modpack_root: &Path) {
+    let enabled: Vec<&PathBuf> = shaderpacks
+        .iter()
+        .filter_map(|x| x.path.as_ref())
+        .filter(|x| !is_disabled_path(x))
+        .collect();
+    if enabled.len() != 1 {
+        info!("Not activating a shaderpack as {} are enabled", enabled.len());
+        return;
+    }
+    let shaderpacks_dir = modpack_root.join("shaderpacks");
+    let shader_pack = match enabled[0].strip_prefix(&shaderpacks_dir) {
+        // Iris uses '/' for shaderpacks in subfolders on every OS
+        Ok(v) => v
+            .components()
+            .map(|x| x.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"),
+        Err(_) => {
+            warn!("Shaderpack '{:#?}' is not in '{shaderpacks_dir:#?}', not activating it", enabled[0]);
+            return;
+        }
+    };
+    let properties_path = modpack_root.join("config").join("iris.properties");
+    let contents = fs::read_to_string(&properties_path).unwrap_or_default();
+    let selected = contents
+        .lines()
+        .find_map(|x| x.strip_prefix("shaderPack="))
+        .map(|x| x.trim())
+        .filter(|x| !x.is_empty());
+    if let Some(selected) = selected {
+        if selected == shader_pack || shaderpacks_dir.join(selected).exists() {
+            info!("Keeping selected shaderpack '{selected}'");
+            return;
+        }
+    }
+    let mut lines: Vec<&str> = contents
+        .lines()
+        .filter(|x| !x.starts_with("shaderPack=") && !x.starts_with("enableShaders="))
+        .collect();
+    let shader_line = format!("shaderPack={shader_pack}");
+    lines.push(&shader_line);
+    lines.push("enableShaders=true");
+    if let Err(e) = fs::create_dir_all(modpack_root.join("config")) {
+        warn!("Failed to create config directory, not activating shaderpack: {e}");
+        return;
+    }
+    match write_atomic(&properties_path, lines.join("\n") + "\n") {
+        Ok(_) => info!("Activated shaderpack '{shader_pack}'"),
+        Err(e) => warn!("Failed to activate shaderpack '{shader_pack}': {e}"),
+    }
+}
+
+/// Adds 'server' to the multiplayer server list in 'servers.dat' unless a server with the same address is already listed.
+/// Other entries and their fields (e.g. icons) are kept as is.
+fn add_default_server(server: &Server, modpack_root: &Path) -> Result<(), InstallError> {
+    let path = modpack_root.join("servers.dat");
+    let mut root = match fs::read(&path) {
+        Ok(v) => match fastnbt::from_bytes::<fastnbt::Value>(&v) {
+            Ok(v) => v,
+            Err(e) => return Err(InstallError::Parse(format!("Invalid 'servers.dat': {e}"))),
+        },
+        Err(_) => fastnbt::Value::Compound(HashMap::new()),
+    };
+    let root_compound = match &mut root {
+        fastnbt::Value::Compound(v) => v,
+        _ => {
+            return Err(InstallError::Parse(String::from(
+                "Expected the root of 'servers.dat' to be a compound",
+            )))
+        }
+    };
+    let servers = match root_compound
+        .entry(String::from("servers"))
+        .or_insert(fastnbt::Value::List(vec![]))
+    {
+        fastnbt::Value::List(v) => v,
+        _ => {
+            return Err(InstallError::Parse(String::from(
+                "Expected 'servers' in 'servers.dat' to be a list",
+            )))
+        }
+    };
+    let listed = servers.iter().any(|x| match x {
+        fastnbt::Value::Compound(entry) => {
+            matches!(entry.get("ip"), Some(fastnbt::Value::String(ip)) if ip == &server.address)
+        }
+        _ => false,
+    });
+    if listed {
+        info!("Server '{}' is already listed", server.address);
+        return Ok(());
+    }
+    servers.push(fastnbt::Value::Compound(HashMap::from([
+        (String::from("name"), fastnbt::Value::String(server.name.clone())),
+        (String::from("ip"), fastnbt::Value::String(server.address.clone())),
+    ])));
+    let bytes = match fastnbt::to_bytes(&root) {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Parse(e.to_string())),
+    };
+    match write_atomic(&path, bytes) {
+        Ok(_) => {
+            info!("Added server '{}' to 'servers.dat'", server.address);
+            Ok(())
+        }
+        Err(e) => Err(InstallError::Io(e.to_string())),
+    }
+}
+
+/// Compares dotted version numbers, e.g. '1.5.3' is older than '1.10.0'. Missing or non numeric parts count as 0.
+fn is_older_version(version: &str, other: &str) -> bool {
+    let parse = |v: &str| -> Vec<u64> {
+        v.trim_start_matches('v')
+            .split('.')
+            .map(|part| {
+                part.chars()
+                    .take_while(|c| c.is_ascii_digit())
+                    .collect::<String>()
+                    .parse()
+                    .unwrap_or(0)
+            })
+            .collect()
+    };
+    let (mut version, mut other) = (parse(version), parse(other));
+    let len = version.len().max(other.len());
+    version.resize(len, 0);
+    other.resize(len, 0);
+    version < other
+}
+
+fn validate_items<T: Downloadable>(
+    items: &[T],
+    r#type: &str,
+    feature_ids: &[&String],
+    problems: &mut Vec<String>,
+) {
+    let mut names = vec![];
+    for item in items {
+        if names.contains(&item.get_name()) {
+            problems.push(format!(
+                "Duplicate {type} name '{}', names must be unique",
+                item.get_name()
+            ));
+        }
+        names.push(item.get_name());
+        if !feature_ids.contains(&item.get_id()) {
+            problems.push(format!(
+                "{type} '{}' references undeclared feature '{}'",
+                item.get_name(),
+                item.get_id()
+            ));
+        }
+        if let Some(subfolder) = item.get_subfolder() {
+            let subfolder = Path::new(subfolder);
+            if subfolder.as_os_str().is_empty()
+                || !subfolder
+                    .components()
+                    .all(|x| matches!(x, std::path::Component::Normal(_)))
+            {
+                problems.push(format!(
+                    "{type} '{}' has invalid subfolder {subfolder:?}, it must be a relative path without '..'",
+                    item.get_name()
+                ));
+            }
+        }
+        if item.get_priority().is_some_and(|x| x < 0) {
+            problems.push(format!(
+                "{type} '{}' has negative priority, it must be 0 or higher",
+                item.get_name()
+            ));
+        }
+        if !["modrinth", "ddl", "mediafire"].contains(&item.get_source().as_str()) {
+            problems.push(format!(
+                "{type} '{}' has unsupported source '{}', expected one of: modrinth, ddl, mediafire",
+                item.get_name(),
+                item.get_source()
+            ));
+        }
+    }
+}
+
+/// Checks semantic constraints serde can't, returning every problem found so they can all be fixed at once
+fn validate_manifest(manifest: &Manifest) -> Result<(), String> {
+    let mut problems = fatal_manifest_problems(manifest);
+    let default = default_id();
+    let mut feature_ids = vec![&default];
+    for feat in &manifest.features {
+        if feature_ids.contains(&&feat.id) {
+            problems.push(format!("Duplicate feature id '{}'", feat.id));
+        }
+        feature_ids.push(&feat.id);
+    }
+    for id in &manifest.enabled_features {
+        if !feature_ids.contains(&id) {
+            problems.push(format!("'enabled_features' references undeclared feature '{id}'"));
+        }
+    }
+    for preset in manifest.presets.iter().flatten() {
+        for id in &preset.features {
+            if !feature_ids.contains(&id) {
+                problems.push(format!(
+                    "Preset '{}' references undeclared feature '{id}'",
+                    preset.name
+                ));
+            }
+        }
+    }
+    validate_items(&manifest.mods, "Mod", &feature_ids, &mut problems);
+    validate_items(&manifest.shaderpacks, "Shaderpack", &feature_ids, &mut problems);
+    validate_items(&manifest.resourcepacks, "Resourcepack", &feature_ids, &mut problems);
+    for inc in &manifest.include {
+        // Default includes enable their id themselves so it doesn't need to be declared
+        if !inc.default && !feature_ids.contains(&&inc.id) {
+            problems.push(format!(
+                "Include '{}' references undeclared feature '{}'",
+                inc.location, inc.id
+            ));
+        }
+        match (&inc.url, &inc.hash) {
+            (Some(_), None) => problems.push(format!(
+                "Include '{}' has a 'url' but no 'hash'",
+                inc.location
+            )),
+            (Some(_), Some(hash)) if hash.len() != 64 || !hash.chars().all(|x| x.is_ascii_hexdigit()) => {
+                problems.push(format!(
+                    "Include '{}' has 'hash' '{hash}' which isn't a sha256 hash",
+                    inc.location
+                ))
+            }
+            (None, Some(_)) => problems.push(format!(
+                "Include '{}' has a 'hash' but no 'url', release includes are hashed in the release body",
+                inc.location
+            )),
+            _ => {}
+        }
+    }
+    for inc in manifest.remote_include.iter().flatten() {
+        if !feature_ids.contains(&&inc.id) {
+            problems.push(format!(
+                "Remote include '{}' references undeclared feature '{}'",
+                inc.location, inc.id
+            ));
+        }
+    }
+    if let Some(endpoint) = &manifest.install_count_endpoint {
+        if !endpoint.starts_with("https://") {
+            problems.push(format!("'install_count_endpoint' must be an https url, got '{endpoint}'"));
+        }
+    }
+    if let (Some(min_mem), Some(max_mem)) = (manifest.min_mem, manifest.max_mem) {
+        if min_mem > max_mem {
+            problems.push(format!(
+                "'min_mem' ({min_mem}) must not be greater than 'max_mem' ({max_mem})"
+            ));
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid manifest '{}':\n- {}",
+            manifest.subtitle,
+            problems.join("\n- ")
+        ))
+    }
+}
+
+/// Problems which would make installing the manifest fail halfway or remove files outside of the modpack.
+/// Unlike the rest of 'validate_manifest' these are never installed, see 'build_profile'.
+fn fatal_manifest_problems(manifest: &Manifest) -> Vec<String> {
+    let mut problems = vec![];
+    if !["fabric", "quilt"].contains(&manifest.loader.r#type.as_str()) {
+        problems.push(format!(
+            "Unsupported loader type '{}', expected one of: fabric, quilt",
+            manifest.loader.r#type
+        ));
+    }
+    // The modpack root would be the folder of every modpack
+    if manifest.uuid.is_empty() {
+        problems.push(String::from("'uuid' must not be empty"));
+    }
+    for inc in &manifest.include {
+        validate_managed_files(&inc.managed_files, "Include", &inc.location, &mut problems);
+    }
+    for inc in manifest.remote_include.iter().flatten() {
+        validate_managed_files(&inc.managed_files, "Remote include", &inc.location, &mut problems);
+    }
+    problems
+}
+
+/// Managed files are removed, so their patterns must stay inside of the modpack root and away from items and user data
+fn validate_managed_files(
+    patterns: &Option<Vec<String>>,
+    kind: &str,
+    location: &str,
+    problems: &mut Vec<String>,
+) {
+    for pattern in patterns.iter().flatten() {
+        if let Some(problem) = managed_files_pattern_problem(pattern) {
+            problems.push(format!("{kind} '{location}' has 'managed_files' pattern '{pattern}' which {problem}"));
+        }
+    }
+}
+
+/// Probes everything installing 'installer_profile' would download, so dead links are found before starting.
+/// Items which are already installed in the same version are skipped. Returns the problems, empty if everything is reachable,
+/// or an error if the release of the includes couldn't be checked at all.
+pub async fn precheck(installer_profile: &InstallerProfile) -> Result<Vec<String>, InstallError> {
+    let manifest = &installer_profile.manifest;
+    let local_manifest = installer_profile.local_manifest.as_ref();
+    let http_client = &installer_profile.http_client;
+    let mut problems = vec![];
+    problems.extend(
+        probe_items(
+            &pending_items(installer_profile, &manifest.mods, local_manifest.map(|x| &x.mods)),
+            &manifest.loader,
+            http_client,
+        )
+        .await,
+    );
+    problems.extend(
+        probe_items(
+            &pending_items(installer_profile, &manifest.shaderpacks, local_manifest.map(|x| &x.shaderpacks)),
+            &manifest.loader,
+            http_client,
+        )
+        .await,
+    );
+    problems.extend(
+        probe_items(
+            &pending_items(installer_profile, &manifest.resourcepacks, local_manifest.map(|x| &x.resourcepacks)),
+            &manifest.loader,
+            http_client,
+        )
+        .await,
+    );
+    let enabled = |id: &String| installer_profile.enabled_features.contains(id);
+    for inc in manifest.remote_include.iter().flatten().filter(|x| enabled(&x.id)) {
+        let name = inc.name.clone().unwrap_or(inc.location.clone());
+        if let Err(e) = probe_url(&name, &inc.location, &[], http_client).await {
+            problems.push(e.to_string());
+        }
+    }
+    for inc in manifest.include.iter().filter(|x| enabled(&x.id)) {
+        if let Some(url) = &inc.url {
+            if let Err(e) = probe_url(&inc.location, url, &[], http_client).await {
+                problems.push(e.to_string());
+            }
+        }
+    }
+    let includes: Vec<&Include> = manifest
+        .include
+        .iter()
+        .filter(|x| enabled(&x.id) && x.url.is_none())
+        .collect();
+    if !includes.is_empty() {
+        problems.extend(
+            check_release_includes(
+                &includes,
+                &installer_profile.modpack_source,
+                &installer_profile.modpack_branch,
+                http_client,
+            )
+            .await?,
+        );
+    }
+    Ok(problems)
+}
+
+/// Enabled items of 'items' which aren't installed in the same version yet
+fn pending_items<T: Downloadable + Clone>(
+    installer_profile: &InstallerProfile,
+    items: &[T],
+    installed_items: Option<&Vec<T>>,
+) -> Vec<T> {
+    items
+        .iter()
+        .filter(|item| {
+            installer_profile.enabled_features.contains(item.get_id())
+                && (item.get_side() == "both" || item.get_side() == installer_profile.target_side.as_str())
+        })
+        .filter(|item| {
+            !installed_items.into_iter().flatten().any(|x| {
+                x.get_name() == item.get_name()
+                    && x.get_version() == item.get_version()
+                    && x.get_path().is_some()
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+/// Fetches the team members of the Modrinth project 'slug' to credit them as authors
+async fn fetch_modrinth_authors(
+    slug: &str,
+    http_client: &CachedHttpClient,
+) -> Result<Vec<Author>, InstallError> {
+    let url = format!("{}project/{slug}/members", http_client.endpoints.modrinth_api);
+    let mut resp = http_client.get_async(url).await?;
+    if resp.status() != StatusCode::OK {
+        return Err(InstallError::Network(format!(
+            "Encountered '{}' error code",
+            resp.status().as_u16()
+        )));
+    }
+    let members: Vec<ModrinthMember> =
+        parse_json(&resp.text().await.unwrap_or_default(), "members").map_err(InstallError::Parse)?;
+    Ok(members
+        .into_iter()
+        .map(|x| Author {
+            link: format!("https://modrinth.com/user/{}", x.user.username),
+            name: x.user.username,
+        })
+        .collect())
+}
+
+/// Fills in the 'authors' of Modrinth items which the manifest left empty.
+/// The result is saved in the local manifest so unchanged items aren't fetched again on updates.
+async fn fill_modrinth_authors<T: Downloadable + Debug>(
+    items: Vec<T>,
+    http_client: &CachedHttpClient,
+) -> Vec<T> {
+    futures::stream::iter(items.into_iter().map(|item| async move {
+        if item.get_source() != "modrinth" || !item.get_authors().is_empty() {
+            return item;
+        }
+        match fetch_modrinth_authors(item.get_location(), http_client).await {
+            Ok(authors) => item.with_authors(authors),
+            Err(e) => {
+                warn!("Failed to fetch the authors of '{}': {e}", item.get_name());
+                item
+            }
+        }
+    }))
+    .buffered(CONCURRENCY)
+    .collect()
+    .await
+}
+
+/// Copies the 'authors' 'fill_modrinth_authors' saved in 'installed' to the items of 'items' without any
+fn fill_cached_authors<T: Downloadable>(items: &mut [T], installed: &[T]) {
+    for item in items.iter_mut().filter(|x| x.get_authors().is_empty()) {
+        if let Some(installed_item) = installed.iter().find(|x| x.get_name() == item.get_name()) {
+            *item = item.with_authors(installed_item.get_authors().clone());
+        }
+    }
+}
+
+async fn probe_items<T: Downloadable + Debug + Sync>(
+    items: &[T],
+    loader: &Loader,
+    http_client: &CachedHttpClient,
+) -> Vec<String> {
+    futures::stream::iter(items.iter().map(|item| async {
+        item.probe(&loader.r#type, &loader.minecraft_version, http_client)
+            .await
+            .err()
+            .map(|e| e.to_string())
+    }))
+    .buffer_unordered(CONCURRENCY)
+    .filter_map(|x| async move { x })
+    .collect()
+    .await
+}
+
+/// Checks a manifest for maintainers before it's published.
+/// 'manifest' is a path or URL, besides validating it every item is resolved and probed without downloading it.
+/// Includes are checked against the release for 'modpack_branch' if 'modpack_source' is passed, e.g. 'Commander07/modpack-test/'.
+/// Returns the list of problems, which is empty if the manifest is fine.
+pub async fn lint_manifest(
+    manifest: &str,
+    modpack_source: Option<&str>,
+    modpack_branch: Option<&str>,
+) -> Result<Vec<String>, InstallError> {
+    let http_client = CachedHttpClient::new();
+    let contents = if manifest.starts_with("http://") || manifest.starts_with("https://") {
+        let mut resp = match http_client.get_nocache(manifest).await {
+            Ok(v) => v,
+            Err(e) => return Err(InstallError::Network(format!("Failed to retrieve manifest: {e}"))),
+        };
+        if resp.status() != StatusCode::OK {
+            return Err(InstallError::Network(format!(
+                "Encountered '{}' error code when retrieving manifest",
+                resp.status().as_u16()
+            )));
+        }
+        match resp.text().await {
+            Ok(v) => v,
+            Err(e) => return Err(InstallError::Network(format!("Failed to retrieve manifest: {e}"))),
+        }
+    } else {
+        match fs::read_to_string(manifest) {
+            Ok(v) => v,
+            Err(e) => return Err(InstallError::Io(format!("Failed to read manifest: {e}"))),
+        }
+    };
+    let location = manifest;
+    let manifest: JsonValue = match parse_json(&contents, "manifest") {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Parse(format!("Failed to parse manifest: {e}"))),
+    };
+    let manifest: Manifest = match resolve_extends(manifest, location, false, &http_client).await {
+        Ok(v) => match parse_json_value(v, "manifest") {
+            Ok(v) => v,
+            Err(e) => return Err(InstallError::Parse(format!("Failed to parse manifest: {e}"))),
+        },
+        Err(e) => return Err(e),
+    };
+    let mut problems = vec![];
+    if manifest.manifest_version != CURRENT_MANIFEST_VERSION {
+        problems.push(format!(
+            "Unsupported manifest version '{}', expected '{CURRENT_MANIFEST_VERSION}'",
+            manifest.manifest_version
+        ));
+    }
+    if let Err(e) = validate_manifest(&manifest) {
+        problems.extend(e.lines().skip(1).map(|x| x.trim_start_matches("- ").to_string()));
+    }
+    problems.extend(probe_items(&manifest.mods, &manifest.loader, &http_client).await);
+    problems.extend(probe_items(&manifest.shaderpacks, &manifest.loader, &http_client).await);
+    problems.extend(probe_items(&manifest.resourcepacks, &manifest.loader, &http_client).await);
+    for inc in manifest.remote_include.iter().flatten() {
+        let name = inc.name.clone().unwrap_or(inc.location.clone());
+        if let Err(e) = probe_url(&name, &inc.location, &[], &http_client).await {
+            problems.push(e.to_string());
+        }
+    }
+    for inc in &manifest.include {
+        if let Some(url) = &inc.url {
+            if let Err(e) = probe_url(&inc.location, url, &[], &http_client).await {
+                problems.push(e.to_string());
+            }
+        }
+    }
+    if let Some(modpack_source) = modpack_source {
+        problems.extend(check_duplicate_uuid(&manifest, modpack_source, modpack_branch, &http_client).await?);
+    }
+    // Only includes without a 'url' are downloaded from the release
+    let includes: Vec<&Include> = manifest.include.iter().filter(|x| x.url.is_none()).collect();
+    if includes.is_empty() {
+        return Ok(problems);
+    }
+    let (modpack_source, modpack_branch) = match (modpack_source, modpack_branch) {
+        (Some(source), Some(branch)) => (source, branch),
+        _ => return Ok(problems),
+    };
+    problems.extend(check_release_includes(&includes, modpack_source, modpack_branch, &http_client).await?);
+    Ok(problems)
+}
+
+/// Checks that no other branch of 'modpack_source' uses the 'uuid' of 'manifest', they would be installed into the same folder.
+/// Branches without a manifest are skipped.
+async fn check_duplicate_uuid(
+    manifest: &Manifest,
+    modpack_source: &str,
+    modpack_branch: Option<&str>,
+    http_client: &CachedHttpClient,
+) -> Result<Vec<String>, InstallError> {
+    let branches = fetch_branches(modpack_source.to_string()).await?;
+    let mut problems = vec![];
+    for branch in branches {
+        if Some(branch.name.as_str()) == modpack_branch {
+            continue;
+        }
+        if let Ok(other) = fetch_manifest(modpack_source, &branch.name, http_client).await {
+            if other.uuid == manifest.uuid {
+                problems.push(format!(
+                    "Branch '{}' uses the same 'uuid' '{}', both would be installed into the same folder",
+                    branch.name, manifest.uuid
+                ));
+            }
+        }
+    }
+    Ok(problems)
+}
+
+/// Checks that the release for 'modpack_branch' has an asset and hash for each of 'includes'
+async fn check_release_includes(
+    includes: &[&Include],
+    modpack_source: &str,
+    modpack_branch: &str,
+    http_client: &CachedHttpClient,
+) -> Result<Vec<String>, InstallError> {
+    let mut problems = vec![];
+    let release_url =
+        http_client.endpoints.github_api.clone() + modpack_source + "releases/tags/" + modpack_branch;
+    let mut release_resp = match http_client.get_async(release_url.clone()).await {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Network(format!("Failed to retrieve release: {e}"))),
+    };
+    if release_resp.status() != StatusCode::OK {
+        http_client.invalidate(release_url).await;
+        problems.push(format!(
+            "Encountered '{}' error code when retrieving release '{modpack_branch}' for includes",
+            release_resp.status().as_u16()
+        ));
+        return Ok(problems);
+    }
+    let release: GithubRelease = match serde_json::from_str(&release_resp.text().await.unwrap_or_default()) {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Parse(format!("Failed to parse release: {e}"))),
+    };
+    let hash_pairs: HashMap<String, String> = match release.body.as_deref().map(serde_json::from_str) {
+        Some(Ok(v)) => v,
+        _ => {
+            problems.push(format!("Release '{modpack_branch}' has no hash pairs in its body"));
+            HashMap::new()
+        }
+    };
+    for inc in includes {
+        let inc_zip_name = inc.id.clone() + ".zip";
+        if !release.assets.iter().any(|x| x.name == inc_zip_name) {
+            problems.push(format!("Include '{}' has no '{inc_zip_name}' release asset", inc.location));
+        }
+        if !hash_pairs.contains_key(&inc_zip_name) {
+            problems.push(format!("Include '{}' has no hash for '{inc_zip_name}'", inc.location));
+        }
+    }
+    Ok(problems)
+}
+
+/// How the items of a new manifest differ from the installed ones, see 'diff_items'
+struct ItemDiff<'a, T> {
+    /// Items which aren't installed yet
+    added: Vec<&'a T>,
+    /// Installed items and the items replacing them
+    updated: Vec<(&'a T, &'a T)>,
+    /// Installed items which are kept as they are
+    unchanged: Vec<&'a T>,
+    /// Installed items which are no longer in the new manifest
+    removed: Vec<&'a T>,
+}
+
+/// Matches 'items' with 'installed_items' by name without touching any files, 'remove_old_items' acts on the result
+fn diff_items<'a, T: Downloadable>(items: &'a [T], installed_items: &'a [T]) -> ItemDiff<'a, T> {
+    let mut diff = ItemDiff {
+        added: vec![],
+        updated: vec![],
+        unchanged: vec![],
+        removed: vec![],
+    };
+    for item in items {
+        match installed_items
+            .iter()
+            .find(|installed_item| installed_item.get_name() == item.get_name())
+        {
+            None => diff.added.push(item),
+            // A moved (or reprioritized) item has to be downloaded again to end up at its new path
+            Some(installed_item)
+                if installed_item.get_version() == item.get_version()
+                    && installed_item.get_subfolder() == item.get_subfolder()
+                    && installed_item.get_priority() == item.get_priority() =>
+            {
+                diff.unchanged.push(installed_item)
+            }
+            Some(installed_item) => diff.updated.push((installed_item, item)),
+        }
+    }
+    diff.removed = installed_items
+        .iter()
+        .filter(|x| !items.iter().any(|item| item.get_name() == x.get_name()))
+        .collect();
+    diff
+}
+
+fn remove_old_items<T: Downloadable + Clone + Debug>(
+    items: &[T],
+    installed_items: &[T],
+    file_system: &dyn FileSystem,
+) -> Vec<T> {
+    let diff = diff_items(items, installed_items);
+    for installed_item in diff.updated.iter().map(|x| x.0).chain(diff.removed.iter().copied()) {
+        if let Some(path) = installed_item.get_path().as_ref() {
+            // For items from the shared store this only removes this modpack's link
+            let _ = file_system.remove_file(path);
+        } else {
+            warn!("Missing 'path' field on {installed_item:#?}")
+        }
+    }
+    items
+        .iter()
+        .map(|item| {
+            diff.unchanged
+                .iter()
+                .find(|x| x.get_name() == item.get_name())
+                .map_or_else(|| item.clone(), |x| (*x).clone())
+        })
+        .collect()
+}
+
+/// Whether the feature selection of 'installer_profile' enables a feature with includes which 'local_manifest' didn't have
+fn enables_includes(installer_profile: &InstallerProfile, local_manifest: &Manifest) -> bool {
+    let newly_enabled = |id: &String| {
+        installer_profile.enabled_features.contains(id) && !local_manifest.enabled_features.contains(id)
+    };
+    installer_profile.manifest.include.iter().any(|x| newly_enabled(&x.id))
+        || installer_profile
+            .manifest
+            .remote_include
+            .iter()
+            .flatten()
+            .any(|x| newly_enabled(&x.id))
+}
+
+/// Applies a changed feature selection to an installed modpack of the same version.
+/// Only items of toggled features are downloaded, enabled or disabled and includes of disabled features are removed,
+/// the loader, launcher profile and remaining includes are left alone.
+async fn modify_features<F: FnMut() + Clone>(
+    installer_profile: &InstallerProfile,
+    local_manifest: Manifest,
+    progress_callback: F,
+) -> Result<InstallReport, InstallError> {
+    info!("Modifying features of modpack");
+    let modpack_root = &get_modpack_root(
+        installer_profile.selected_launcher()?,
+        &installer_profile.manifest.uuid,
+    );
+    reset_http_client(installer_profile);
+    claim_installed_paths(&installer_profile.http_client, &local_manifest);
+    // The local items have the paths of what is already installed so 'download_helper' only acts on toggled ones
+    let (mods, skipped_mods) = match download_helper(
+        local_manifest.mods.clone(),
+        installer_profile,
+        modpack_root,
+        progress_callback.clone(),
+    )
+    .await
+    {
+        Ok(v) => v,
+        Err(e) => return Err(e.into()),
+    };
+    let (shaderpacks, skipped_shaderpacks) = match download_helper(
+        local_manifest.shaderpacks.clone(),
+        installer_profile,
+        modpack_root,
+        progress_callback.clone(),
+    )
+    .await
+    {
+        Ok(v) => v,
+        Err(e) => {
+            remove_new_files(&existing_paths(&local_manifest.mods), &mods);
+            return Err(e.into());
+        }
+    };
+    let (resourcepacks, skipped_resourcepacks) = match download_helper(
+        local_manifest.resourcepacks.clone(),
+        installer_profile,
+        modpack_root,
+        progress_callback,
+    )
+    .await
+    {
+        Ok(v) => v,
+        Err(e) => {
+            remove_new_files(&existing_paths(&local_manifest.mods), &mods);
+            remove_new_files(&existing_paths(&local_manifest.shaderpacks), &shaderpacks);
+            return Err(e.into());
+        }
+    };
+    let remote_include_ids: HashMap<&String, &String> = installer_profile
+        .manifest
+        .remote_include
+        .iter()
+        .flatten()
+        .map(|x| (&x.location, &x.id))
+        .collect();
+    let item_paths: HashSet<PathBuf> = [existing_paths(&mods), existing_paths(&shaderpacks), existing_paths(&resourcepacks)]
+        .concat()
+        .into_iter()
+        .chain(manifest_item_paths(&local_manifest))
+        .collect();
+    // Includes are keyed by their zip name ('<id>.zip') or by their location for remote includes
+    let included_files = local_manifest.included_files.clone().map(|included_files| {
+        included_files
+            .into_iter()
+            .filter(|(name, included)| {
+                let id = match remote_include_ids.get(name) {
+                    Some(id) => id.to_string(),
+                    None => name.replace(".zip", ""),
+                };
+                if installer_profile.enabled_features.contains(&id) {
+                    return true;
+                }
+                for file in &included.files {
+                    info!("Removing: '{file}'");
+                    let _ = installer_profile.file_system.remove_file(Path::new(file));
+                }
+                remove_managed_files(modpack_root, include_managed_files(&local_manifest, name), &item_paths);
+                false
+            })
+            .collect()
+    });
+    // After removing includes of disabled features as those may ship their own 'iris.properties'
+    if installer_profile.manifest.activate_shaderpack.unwrap_or(false) {
+        activate_shaderpack(&shaderpacks, modpack_root);
+    }
+    let http_client = &installer_profile.http_client;
+    let mods = fill_modrinth_authors(mods, http_client).await;
+    let shaderpacks = fill_modrinth_authors(shaderpacks, http_client).await;
+    let resourcepacks = fill_modrinth_authors(resourcepacks, http_client).await;
+    let local_manifest = Manifest {
+        mods,
+        shaderpacks,
+        resourcepacks,
+        enabled_features: installer_profile.enabled_features.clone(),
+        included_files,
+        ..local_manifest
+    };
+    let local_manifest_json = match serde_json::to_string(&local_manifest) {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Parse(format!("Failed to serialize 'manifest.json': {e}"))),
+    };
+    if let Err(e) = installer_profile.file_system.write(
+        &modpack_root.join(Path::new("manifest.json")),
+        local_manifest_json.as_bytes(),
+    ) {
+        return Err(InstallError::Io(format!("Failed to save a local copy of 'manifest.json': {e}")));
+    }
+    separate_user_data(installer_profile, modpack_root);
+    info!("Modified features of modpack!");
+    Ok(InstallReport {
+        skipped: [skipped_mods, skipped_shaderpacks, skipped_resourcepacks].concat(),
+        ..InstallReport::default()
+    })
+}
+
+// Why haven't I split this into multiple files? That's a good question. I forgot, and I can't be bothered to do it now.
+// TODO(Split project into multiple files to improve maintainability)
+pub async fn update<F: FnMut() -> () + Clone>(
+    installer_profile: &InstallerProfile,
+    side: Side,
+    progress_callback: F,
+) -> Result<InstallReport, InstallError> {
+    info!("Updating modpack");
+    let mut side_profile = installer_profile.clone();
+    side_profile.target_side = side;
+    let installer_profile = &side_profile;
+    info!("installer_profile = {installer_profile:#?}");
+    let local_manifest: Manifest = match fs::read_to_string(
+        get_modpack_root(
+            installer_profile.selected_launcher()?,
+            &installer_profile.manifest.uuid,
+        )
+        .join(Path::new("manifest.json")),
+    ) {
+        Ok(contents) => match parse_json(&contents, "manifest") {
+            Ok(parsed) => parsed,
+            // E.g. written by an older installer with an incompatible schema, the modpack root is kept and overwritten
+            Err(err) => {
+                error!("Failed to parse local manifest, installing the modpack again: {err}");
+                let mut install_profile = installer_profile.clone();
+                install_profile.local_manifest = None;
+                return install(&install_profile, side, progress_callback).await;
+            }
+        },
+        Err(err) => return Err(InstallError::Io(format!("Failed to read local manifest: {err}"))),
+    };
+    if local_manifest.modpack_version == installer_profile.manifest.modpack_version
+        && !enables_includes(installer_profile, &local_manifest)
+    {
+        // Reported like an install, only the items of toggled features are downloaded
+        installer_profile.emit(InstallEvent::Started {
+            total: count_pending_items(&local_manifest.mods, installer_profile)
+                + count_pending_items(&local_manifest.shaderpacks, installer_profile)
+                + count_pending_items(&local_manifest.resourcepacks, installer_profile),
+        }).await;
+        let res = modify_features(installer_profile, local_manifest, progress_callback).await;
+        match &res {
+            Ok(_) => installer_profile.emit(InstallEvent::Finished).await,
+            Err(e) => installer_profile.emit(InstallEvent::Failed { error: e.to_string() }).await,
+        }
+        return res;
+    }
+    let file_system = installer_profile.file_system.as_ref();
+    let new_mods = remove_old_items(
+        &installer_profile.manifest.mods,
+        &local_manifest.mods,
+        file_system,
+    );
+    let new_shaderpacks = remove_old_items(
+        &installer_profile.manifest.shaderpacks,
+        &local_manifest.shaderpacks,
+        file_system,
+    );
+    let new_resourcepacks = remove_old_items(
+        &installer_profile.manifest.resourcepacks,
+        &local_manifest.resourcepacks,
+        file_system,
+    );
+    let mut update_profile = installer_profile.clone();
+    update_profile.manifest.mods = new_mods;
+    update_profile.manifest.shaderpacks = new_shaderpacks;
+    update_profile.manifest.resourcepacks = new_resourcepacks;
+    // 'install' compares against this to decide if the loader has to be downloaded again
+    update_profile.local_manifest = Some(local_manifest);
+    let e = install(&update_profile, side, progress_callback).await;
+    if e.is_ok() {
+        info!("Updated modpack");
+    } else {
+        error!("Failed to update modpack: {e:#?}")
+    }
+    e
+}
+
+/// Wipes the modpack and installs it again from the current manifest, keeping the features the user had enabled
+pub async fn reinstall<F: FnMut() + Clone>(
+    installer_profile: &InstallerProfile,
+    side: Side,
+    progress_callback: F,
+) -> Result<InstallReport, InstallError> {
+    info!("Reinstalling modpack");
+    let launcher = installer_profile.selected_launcher()?;
+    // Read before wiping as the local manifest is deleted by 'uninstall'
+    let local_manifest = match fs::read_to_string(
+        get_modpack_root(launcher, &installer_profile.manifest.uuid).join(Path::new("manifest.json")),
+    ) {
+        Ok(contents) => serde_json::from_str::<Manifest>(&contents).ok(),
+        Err(_) => None,
+    };
+    let enabled_features = match &local_manifest {
+        Some(local_manifest) => local_manifest.enabled_features.clone(),
+        None => installer_profile.enabled_features.clone(),
+    };
+    if let Err(e) = uninstall(launcher, &installer_profile.manifest.uuid) {
+        return Err(InstallError::Io(format!("Failed to remove modpack: {e}")));
+    }
+    let mut reinstall_profile = installer_profile.clone();
+    reinstall_profile.enabled_features = enabled_features.clone();
+    reinstall_profile.manifest.enabled_features = enabled_features;
+    reinstall_profile.installed = false;
+    reinstall_profile.update_available = false;
+    reinstall_profile.local_manifest = None;
+    // Keeps a resolved "latest" loader so reinstalling doesn't change the loader version
+    if let Some(local_manifest) = local_manifest {
+        if reinstall_profile.manifest.loader.version == LATEST_LOADER
+            && local_manifest.loader.r#type == reinstall_profile.manifest.loader.r#type
+            && local_manifest.loader.minecraft_version == reinstall_profile.manifest.loader.minecraft_version
+        {
+            reinstall_profile.manifest.loader = local_manifest.loader;
+        }
+    }
+    let e = install(&reinstall_profile, side, progress_callback).await;
+    if e.is_ok() {
+        info!("Reinstalled modpack");
+    } else {
+        error!("Failed to reinstall modpack: {e:#?}")
+    }
+    e
+}
+
+/// Runs the manifest's 'post_install_command' in the modpack root with the permissions of the user.
+/// Never called without the user approving the exact command, the command runs on its own thread so the GUI stays responsive.
+pub async fn run_post_install_command(installer_profile: &InstallerProfile) -> Result<(), InstallError> {
+    let command = match &installer_profile.manifest.post_install_command {
+        Some(v) => v.clone(),
+        None => return Ok(()),
+    };
+    let modpack_root = get_modpack_root(
+        installer_profile.selected_launcher()?,
+        &installer_profile.manifest.uuid,
+    );
+    info!("Running post install command '{command}' in {modpack_root:#?}");
+    let (sender, receiver) = oneshot::channel();
+    std::thread::spawn(move || {
+        let mut shell = if cfg!(target_os = "windows") {
+            let mut shell = std::process::Command::new("cmd");
+            shell.arg("/C");
+            shell
+        } else {
+            let mut shell = std::process::Command::new("sh");
+            shell.arg("-c");
+            shell
+        };
+        let _ = sender.send(shell.arg(&command).current_dir(&modpack_root).status());
+    });
+    match receiver.await {
+        Ok(Ok(status)) if status.success() => {
+            info!("Post install command finished");
+            Ok(())
+        }
+        Ok(Ok(status)) => Err(InstallError::Other(format!("Post install command failed with {status}"))),
+        Ok(Err(e)) => Err(InstallError::Io(format!("Failed to run post install command: {e}"))),
+        Err(_) => Err(InstallError::Other(String::from("Post install command was interrupted"))),
+    }
+}
+
+/// Directories of the modpack root which only contain items the installer downloaded (or includes extracted)
+const MANAGED_DIRS: [&str; 3] = ["mods", "shaderpacks", "resourcepacks"];
+
+/// Lists files in the 'MANAGED_DIRS' which aren't an item or included file of the installed modpack,
+/// e.g. mods the user added themselves or leftovers of a crashed update. Nothing outside of these directories is listed.
+pub fn find_unmanaged_files(installer_profile: &InstallerProfile) -> Result<Vec<PathBuf>, InstallError> {
+    let modpack_root = get_modpack_root(
+        installer_profile.selected_launcher()?,
+        &installer_profile.manifest.uuid,
+    );
+    // Read from disk as the profile's local manifest is outdated after installing
+    let local_manifest: Manifest = match fs::read_to_string(modpack_root.join("manifest.json")) {
+        Ok(v) => parse_json(&v, "manifest").map_err(InstallError::Parse)?,
+        Err(e) => return Err(InstallError::Io(format!("Failed to read local manifest: {e}"))),
+    };
+    let mut managed: HashSet<PathBuf> = HashSet::new();
+    managed.extend(local_manifest.mods.iter().filter_map(|x| x.path.clone()));
+    managed.extend(local_manifest.shaderpacks.iter().filter_map(|x| x.path.clone()));
+    managed.extend(local_manifest.resourcepacks.iter().filter_map(|x| x.path.clone()));
+    for included in local_manifest.included_files.iter().flat_map(|x| x.values()) {
+        managed.extend(included.files.iter().map(PathBuf::from));
+    }
+    let mut unmanaged = vec![];
+    for dir in MANAGED_DIRS {
+        collect_unmanaged_files(&modpack_root.join(dir), &managed, &mut unmanaged);
+    }
+    Ok(unmanaged)
+}
+
+fn collect_unmanaged_files(dir: &Path, managed: &HashSet<PathBuf>, unmanaged: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_unmanaged_files(&path, managed, unmanaged);
+        } else if !managed.contains(&path) {
+            unmanaged.push(path);
+        }
+    }
+}
+
+/// Removes files returned by 'find_unmanaged_files' after the user confirmed it.
+/// Paths outside of the 'MANAGED_DIRS' are refused so this can never delete anything else.
+pub fn remove_unmanaged_files(
+    installer_profile: &InstallerProfile,
+    files: &[PathBuf],
+) -> Result<(), InstallError> {
+    let modpack_root = get_modpack_root(
+        installer_profile.selected_launcher()?,
+        &installer_profile.manifest.uuid,
+    );
+    let mut failed = vec![];
+    for file in files {
+        let is_managed_dir = MANAGED_DIRS
+            .iter()
+            .any(|dir| file.starts_with(modpack_root.join(dir)));
+        if !is_managed_dir
+            || file
+                .components()
+                .any(|x| matches!(x, std::path::Component::ParentDir))
+        {
+            failed.push(format!("{file:#?} is not in a managed directory"));
+            continue;
+        }
+        match fs::remove_file(file) {
+            Ok(_) => info!("Removed unmanaged file {file:#?}"),
+            Err(e) => failed.push(format!("Failed to remove {file:#?}: {e}")),
+        }
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(InstallError::Io(failed.join("\n")))
+    }
+}
+
+/// Zips the installed modpack into 'dest', e.g. to copy it to a server. Loader version folders are left out.
+pub fn export_pack(installer_profile: &InstallerProfile, dest: &Path) -> Result<(), InstallError> {
+    let modpack_root = get_modpack_root(
+        installer_profile.selected_launcher()?,
+        &installer_profile.manifest.uuid,
+    );
+    info!("Exporting {modpack_root:#?} to {dest:#?}");
+    let tmp = part_path(dest);
+    let file = match File::create(&tmp) {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Io(format!("Failed to create {tmp:#?}: {e}"))),
+    };
+    let mut zip = zip::ZipWriter::new(file);
+    let res = zip_dir(&mut zip, &modpack_root, &modpack_root).and_then(|_| zip.finish());
+    if let Err(e) = res {
+        let _ = fs::remove_file(&tmp);
+        return Err(InstallError::Io(format!("Failed to export modpack: {e}")));
+    }
+    if let Err(e) = fs::rename(&tmp, dest) {
+        return Err(InstallError::Io(format!("Failed to move export to {dest:#?}: {e}")));
+    }
+    info!("Exported modpack!");
+    Ok(())
+}
+
+fn zip_dir(zip: &mut zip::ZipWriter<File>, root: &Path, dir: &Path) -> zip::result::ZipResult<()> {
+    let options = zip::write::SimpleFileOptions::default();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let relative = path.strip_prefix(root).unwrap(); // 'path' is always inside of 'root'
+        // Loader versions belong to the launcher and '.part' files are incomplete writes
+        if relative == Path::new("versions") || path.extension().is_some_and(|x| x == "part") {
+            continue;
+        }
+        if path.is_dir() {
+            zip.add_directory_from_path(relative, options)?;
+            zip_dir(zip, root, &path)?;
+        } else {
+            zip.start_file_from_path(relative, options)?;
+            std::io::copy(&mut File::open(&path)?, zip)?;
+        }
+    }
+    Ok(())
+}
+
+/// Moves the modpack 'uuid' from the 'from' launcher to the 'to' launcher without downloading it again.
+/// Vanilla keeps it in '.WC_OVHL/{uuid}' and MultiMC in 'instances/{uuid}/.minecraft', the launcher profile is
+/// recreated for 'to' and removed from 'from'.
+pub async fn migrate(from: &Launcher, to: &Launcher, uuid: &str) -> Result<(), InstallError> {
+    if from == to {
+        return Err(InstallError::Other(format!("'{uuid}' is already installed in {to}")));
+    }
+    let old_root = get_modpack_dir(from, uuid);
+    let new_root = get_modpack_dir(to, uuid);
+    let local_manifest: Manifest = match fs::read_to_string(old_root.join("manifest.json")) {
+        Ok(v) => parse_json(&v, "manifest").map_err(InstallError::Parse)?,
+        Err(e) => return Err(InstallError::Io(format!("'{uuid}' is not installed in {from}: {e}"))),
+    };
+    // An empty folder is left behind by 'uninstall'
+    if new_root.is_dir() {
+        match fs::read_dir(&new_root).map(|mut x| x.next().is_none()) {
+            Ok(true) => {
+                if let Err(e) = fs::remove_dir(&new_root) {
+                    return Err(InstallError::Io(format!("Failed to remove {new_root:#?}: {e}")));
+                }
+            }
+            _ => return Err(InstallError::Other(format!("'{uuid}' is already installed in {to}"))),
+        }
+    }
+    info!("Migrating {old_root:#?} to {new_root:#?}");
+    if let Err(e) = move_dir(&old_root, &new_root) {
+        return Err(InstallError::Io(format!("Failed to move {old_root:#?} to {new_root:#?}: {e}")));
+    }
+    let local_manifest = rebase_local_manifest(local_manifest, &old_root, &new_root);
+    let contents = serde_json::to_string(&local_manifest).expect("Failed to serialize 'manifest.json'!");
+    if let Err(e) = write_atomic(&new_root.join("manifest.json"), contents) {
+        let _ = move_dir(&new_root, &old_root);
+        return Err(InstallError::Io(format!("Failed to update the local manifest: {e}")));
+    }
+    // 'source' is the modpack source followed by the branch, e.g. 'owner/repo/main'
+    let (modpack_source, modpack_branch) = match local_manifest.source.as_deref().and_then(|x| {
+        let mut parts = x.splitn(3, '/');
+        Some((
+            format!("{}/{}/", parts.next()?, parts.next()?),
+            parts.next()?.to_string(),
+        ))
+    }) {
+        Some(v) => v,
+        None => (String::from(REPO), String::new()),
+    };
+    let installer_profile = match build_profile(
+        local_manifest,
+        modpack_source,
+        modpack_branch,
+        Some(to.clone()),
+        CachedHttpClient::new(),
+    ) {
+        Ok(v) => v,
+        Err(e) => {
+            let _ = move_dir(&new_root, &old_root);
+            return Err(e);
+        }
+    };
+    let manifest = &installer_profile.manifest;
+    // MultiMC installs the loader itself
+    if let Launcher::Vanilla(root) = to {
+        if let Err(e) = manifest
+            .loader
+            .download(
+                &get_minecraft_folder_in(root),
+                &manifest.loader.r#type,
+                &installer_profile.http_client,
+                false,
+                false,
+            )
+            .await
+        {
+            let _ = move_dir(&new_root, &old_root);
+            return Err(e.into());
+        }
+    }
+    let icon_img = if manifest.icon {
+        download_icon(&installer_profile).await
+    } else {
+        None
+    };
+    if let Err(e) = create_launcher_profile(&installer_profile, icon_img) {
+        let _ = move_dir(&new_root, &old_root);
+        return Err(e.into());
+    }
+    if let Err(e) = remove_launcher_profile(from, uuid) {
+        warn!("Failed to remove the launcher profile of '{uuid}' from {from}: {e}");
+    }
+    info!("Migrated modpack!");
+    Ok(())
+}
+
+/// Renames 'from' to 'to', copying it instead if that fails, e.g. because they are on different drives
+fn move_dir(from: &Path, to: &Path) -> Result<(), std::io::Error> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    if let Err(e) = copy_dir(from, to) {
+        let _ = fs::remove_dir_all(to);
+        return Err(e);
+    }
+    fs::remove_dir_all(from)
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<(), std::io::Error> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let path = entry?.path();
+        let dest = to.join(path.file_name().unwrap()); // 'read_dir' entries always have a filename
+        if path.is_dir() {
+            copy_dir(&path, &dest)?;
+        } else {
+            fs::copy(&path, &dest)?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns 'path' relative to 'to' instead of 'from', paths outside of 'from' are returned as is
+fn rebase_path(path: &Path, from: &Path, to: &Path) -> PathBuf {
+    match path.strip_prefix(from) {
+        Ok(v) => to.join(v),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+fn rebase_items<T: Downloadable>(items: Vec<T>, from: &Path, to: &Path) -> Vec<T> {
+    items
+        .into_iter()
+        .map(|x| {
+            let path = x.get_path().as_ref().map(|x| rebase_path(x, from, to));
+            x.with_path(path)
+        })
+        .collect()
+}
+
+/// Moves the paths the local manifest stores for items and included files from 'from' to 'to', see 'migrate'
+fn rebase_local_manifest(manifest: Manifest, from: &Path, to: &Path) -> Manifest {
+    let rebase = |x: &str| rebase_path(Path::new(x), from, to).to_string_lossy().to_string();
+    Manifest {
+        mods: rebase_items(manifest.mods, from, to),
+        shaderpacks: rebase_items(manifest.shaderpacks, from, to),
+        resourcepacks: rebase_items(manifest.resourcepacks, from, to),
+        included_files: manifest.included_files.map(|included_files| {
+            included_files
+                .into_iter()
+                .map(|(name, included)| {
+                    let included = Included {
+                        files: included.files.iter().map(|x| rebase(x)).collect(),
+                        checksums: included
+                            .checksums
+                            .into_iter()
+                            .map(|(file, checksum)| (rebase(&file), checksum))
+                            .collect(),
+                        ..included
+                    };
+                    (name, included)
+                })
+                .collect()
+        }),
+        ..manifest
+    }
+}
+
+fn get_launcher(string_representation: &str) -> Result<Launcher, InstallError> {
+    let mut launcher = string_representation.split('-').collect::<Vec<_>>();
+    match *launcher.first().unwrap() {
+        "vanilla" => Ok(Launcher::Vanilla(get_app_data()?)),
+        "multimc" => {
+            let data_dir = get_multimc_folder(
+                launcher
+                    .last()
+                    .expect("Missing data dir segement in MultiMC!"),
+            );
+            match data_dir {
+                Ok(path) => Ok(Launcher::MultiMC(path)),
+                Err(e) => Err(e),
+            }
+        }
+        "custom" => {
+            let data_dir = PathBuf::from(launcher.split_off(1).join("-"));
+            match data_dir.metadata() {
+                Ok(metadata) => {
+                    if !metadata.is_dir() || !data_dir.join("instances").is_dir() {
+                        return Err(InstallError::LauncherNotFound(String::from(
+                            "MultiMC directory is not a valid directory!",
+                        )));
+                    }
+                }
+                Err(e) => return Err(InstallError::LauncherNotFound(e.to_string())),
+            }
+            Ok(Launcher::MultiMC(data_dir))
+        }
+        "modrinth" => match get_modrinth_folder() {
+            Ok(path) => Ok(Launcher::Modrinth(path)),
+            Err(e) => Err(e),
+        },
+        _ => Err(InstallError::LauncherNotFound(String::from("Invalid launcher!"))),
+    }
+}
+
+pub fn run() {
+    let (app_data, config_dir) = match (get_app_data(), get_config_path()) {
+        (Ok(app_data), Ok(config_dir)) => (app_data, config_dir),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("{e}");
+            rfd::MessageDialog::new()
+                .set_level(rfd::MessageLevel::Error)
+                .set_title(DEFAULT_WINDOW_TITLE)
+                .set_description(e.to_string())
+                .show();
+            return;
+        }
+    };
+    let legacy_path = app_data.join(".WC_OVHL");
+
+    if legacy_path.exists() && get_sandbox_root().is_none() {
+        println!("Detected legacy .WC_OVHL directory. Attempting to move it to the new location.");
+
+        match fs::rename(legacy_path, &config_dir) {
+            Ok(_) => println!("Successfully moved legacy directory to its new destination."),
+            Err(e) => println!("Could not move legacy directory to the new location. Error: {}", e),
+        };
+    }
+
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir!");
+
+    CombinedLogger::init(vec![
+        TermLogger::new(
+            LevelFilter::Info,
+            LogConfig::default(),
+            TerminalMode::Mixed,
+            ColorChoice::Auto,
+        ),
+        WriteLogger::new(
+            LevelFilter::Info,
+            LogConfig::default(),
+            File::create(config_dir.join("installer.log")).unwrap(),
+        ),
+    ])
+    .unwrap();
+    panic::set_hook(Box::new(|info| {
+        let payload = if let Some(string) = info.payload().downcast_ref::<String>() {
+            format!("{string}")
+        } else if let Some(str) = info.payload().downcast_ref::<&'static str>() {
+            format!("{str}")
+        } else {
+            format!("{:?}", info.payload())
+        };
+        let backtrace = Backtrace::force_capture();
+        error!("The installer panicked! This is a bug.\n{info:#?}\nPayload: {payload}\nBacktrace: {backtrace}");
+    }));
+    info!("Installer version: {}", env!("CARGO_PKG_VERSION"));
+    if let Some(sandbox) = get_sandbox_root() {
+        info!("Installing into sandbox: {sandbox:#?}");
+        fs::create_dir_all(get_minecraft_folder_in(&app_data)).expect("Failed to create sandbox!");
+    }
+    let platform_info = PlatformInfo::new().expect("Unable to determine platform info");
+    info!("System information:\n\tSysname: {}\n\tRelease: {}\n\tVersion: {}\n\tArchitecture: {}\n\tOsname: {}",platform_info.sysname().to_string_lossy(), platform_info.release().to_string_lossy(), platform_info.version().to_string_lossy(), platform_info.machine().to_string_lossy(), platform_info.osname().to_string_lossy());
+    let icon = image::load_from_memory(include_bytes!("assets/icon.png")).unwrap();
+    let config_path = config_dir.join("config.json");
+    let config: Config;
+    if config_path.exists() {
+        config = serde_json::from_slice(&fs::read(&config_path).expect("Failed to read config!"))
+            .expect("Failed to load config!");
+    } else {
+        config = Config {
+            launcher: String::from("vanilla"),
+            first_launch: Some(true),
+            proxy: None,
+            shared_store: None,
+            locale: None,
+            show_beta: None,
+            release_prefixes: None,
+            max_mem: None,
+            pinned_ca: None,
+            endpoints: None,
+            force_manifest_jvm: None,
+            insecure_ddl: None,
+            install_counting: None,
+            compact_view: None,
+            separate_user_data: None,
+            host_auth: None,
+            trusted_sources: None,
+            max_redirects: None,
+            redirect_limits: None,
+        };
+        save_config(&config_path, &config).expect("Failed to write config!");
+    }
+    info!("Running installer with config: {config:#?}");
+    if let Ok(launcher) = get_launcher(&config.launcher) {
+        clean_store(&get_store_root(&launcher));
+    }
+    let primary_manifest = futures::executor::block_on(prefetch_manifest(&config));
+    let (title, size) = match primary_manifest {
+        Some(manifest) => (
+            format!("{} Installer", manifest.name),
+            (
+                manifest.window_width.unwrap_or(DEFAULT_WINDOW_SIZE.0),
+                manifest.window_height.unwrap_or(DEFAULT_WINDOW_SIZE.1),
+            ),
+        ),
+        None => (String::from(DEFAULT_WINDOW_TITLE), DEFAULT_WINDOW_SIZE),
+    };
+    LaunchBuilder::desktop().with_cfg(
+        DioxusConfig::new().with_window(
+                WindowBuilder::new()
+                    .with_resizable(true)
+                    .with_title(title)
+                    .with_inner_size(LogicalSize::new(size.0, size.1))
+            ).with_icon(
+                Icon::from_rgba(icon.to_rgba8().to_vec(), icon.width(), icon.height()).unwrap(),
+            ).with_data_directory(
+                config_dir
+            ).with_menu(None)
+        ).with_context(gui::AppProps {
+            modpack_source: String::from(REPO),
+            config,
+            config_path,
+        }).launch(gui::app);
+}
+
+/// Fetches the manifest of the newest release so the window can be set up for it before it's created.
+/// Failures only fall back to the default window, the GUI retries fetching and reports errors itself.
+async fn prefetch_manifest(config: &Config) -> Option<Manifest> {
+    let branches = fetch_branches_with_timeout(REPO, PREFETCH_TIMEOUT, false).await.ok()?;
+    let branch = branches
+        .iter()
+        .find(|x| is_release_branch(config, &x.name))
+        .or(branches.first())?;
+    let url = Endpoints::from_config().github_raw + REPO + &branch.name + "/manifest.json";
+    let request = get_request_builder(url, &[])
+        .timeout(PREFETCH_TIMEOUT)
+        .body(())
+        .unwrap();
+    let mut resp = match build_http_client().send_async(request).await {
+        Ok(v) if v.status() == StatusCode::OK => v,
+        Ok(v) => {
+            warn!("Failed to prefetch manifest, got '{}' error code", v.status().as_u16());
+            return None;
+        }
+        Err(e) => {
+            warn!("Failed to prefetch manifest: {e}");
+            return None;
+        }
+    };
+    match parse_json(&resp.text().await.ok()?, "manifest") {
+        Ok(v) => Some(v),
+        Err(e) => {
+            warn!("Failed to parse prefetched manifest: {e}");
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Launcher {
+    Vanilla(PathBuf),
+    MultiMC(PathBuf),
+    Modrinth(PathBuf),
+}
+
+impl Display for Launcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Launcher::Vanilla(_) => write!(f, "Vanilla"),
+            Launcher::MultiMC(_) => write!(f, "MultiMC"),
+            Launcher::Modrinth(_) => write!(f, "Modrinth"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InstallerProfile {
+    manifest: Manifest,
+    http_client: CachedHttpClient,
+    installed: bool,
+    update_available: bool,
+    modpack_source: String,
+    modpack_branch: String,
+    enabled_features: Vec<String>,
+    launcher: Option<Launcher>,
+    local_manifest: Option<Manifest>,
+    /// Checked between downloads, set to abort an in-progress install
+    cancelled: Arc<AtomicBool>,
+    /// Items with a 'side' other than this or "both" are skipped
+    target_side: Side,
+    /// Downloads the loader again even if it's installed, e.g. to repair a broken loader
+    force_loader: bool,
+    events: Option<mpsc::Sender<InstallEvent>>,
+    file_system: Arc<dyn FileSystem>,
+}
+
+/// Progress of 'install' and 'update', see 'InstallerProfile::subscribe'
+#[derive(Debug, Clone, PartialEq)]
+pub enum InstallEvent {
+    Started { total: usize },
+    ItemDownloaded { name: String },
+    /// The item is no longer available and was left out of the install
+    ItemSkipped { name: String },
+    IncludeExtracted { id: String },
+    Finished,
+    Failed { error: String },
+}
+
+/// What an install or update skipped or changed, returned so it can be shown to the user afterwards
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct InstallReport {
+    /// Names of the items which were skipped as they're no longer available
+    pub skipped: Vec<String>,
+    /// Changes made to the memory settings of the modpack, see 'clamp_memory'
+    pub memory_adjustments: Vec<String>,
+}
+
+/// Display names of what updating an installed modpack changes, see 'InstallerProfile::update_changes'
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UpdateChanges {
+    pub added: Vec<String>,
+    pub updated: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl UpdateChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+
+    /// Adds the changes of items of enabled features, removed items count if their feature was installed
+    fn push_items<T: Downloadable>(
+        &mut self,
+        items: &[T],
+        local_manifest: &Manifest,
+        installed_items: &[T],
+        enabled_features: &[String],
+    ) {
+        let diff = diff_items(items, installed_items);
+        let enabled = |item: &T| enabled_features.contains(item.get_id());
+        self.added
+            .extend(diff.added.into_iter().filter(|x| enabled(x)).map(|x| x.get_name().clone()));
+        self.updated.extend(
+            diff.updated
+                .into_iter()
+                .filter(|x| enabled(x.1))
+                .map(|x| x.1.get_name().clone()),
+        );
+        self.removed.extend(
+            diff.removed
+                .into_iter()
+                .filter(|x| local_manifest.enabled_features.contains(x.get_id()))
+                .map(|x| x.get_name().clone()),
+        );
+    }
+}
+
+impl InstallerProfile {
+    /// The launcher the modpack is installed into, 'InstallError::NoLauncher' if none was selected
+    fn selected_launcher(&self) -> Result<&Launcher, InstallError> {
+        self.launcher.as_ref().ok_or(InstallError::NoLauncher)
+    }
+
+    /// Sends progress events of installs and updates done with this profile to 'events'.
+    /// Replaces the previous subscriber if there was one. The install waits while the channel is full so it has to be drained.
+    pub fn subscribe(&mut self, events: mpsc::Sender<InstallEvent>) {
+        self.events = Some(events);
+    }
+
+    /// Returns HTML from the manifest for the GUI. Scripts, event handlers and the like are removed unless the
+    /// manifest sets 'raw_html' and comes from a trusted source, the webview would otherwise run them natively.
+    fn manifest_html(&self, html: &str) -> String {
+        if self.manifest.raw_html && is_trusted_source(&self.modpack_source) {
+            html.to_string()
+        } else {
+            ammonia::clean(html)
+        }
+    }
+
+    /// The manifest with the Modrinth 'authors' fetched during the install filled in, used for the credits
+    pub fn credits_manifest(&self) -> Manifest {
+        let mut manifest = self.manifest.clone();
+        if let Some(local_manifest) = &self.local_manifest {
+            fill_cached_authors(&mut manifest.mods, &local_manifest.mods);
+            fill_cached_authors(&mut manifest.shaderpacks, &local_manifest.shaderpacks);
+            fill_cached_authors(&mut manifest.resourcepacks, &local_manifest.resourcepacks);
+        }
+        manifest
+    }
+
+    /// What 'update' would change compared to the installed modpack, 'None' if it isn't installed.
+    /// Includes from the GitHub release are only reported as added or removed, whether their contents changed is
+    /// only known once the release is fetched.
+    pub fn update_changes(&self) -> Option<UpdateChanges> {
+        let local_manifest = self.local_manifest.as_ref()?;
+        let mut changes = UpdateChanges::default();
+        changes.push_items(&self.manifest.mods, local_manifest, &local_manifest.mods, &self.enabled_features);
+        changes.push_items(
+            &self.manifest.shaderpacks,
+            local_manifest,
+            &local_manifest.shaderpacks,
+            &self.enabled_features,
+        );
+        changes.push_items(
+            &self.manifest.resourcepacks,
+            local_manifest,
+            &local_manifest.resourcepacks,
+            &self.enabled_features,
+        );
+
+        // Keys of 'included_files' are '<id>.zip' for includes and the location for remote includes
+        let included_files = local_manifest.included_files.clone().unwrap_or_default();
+        let mut kept = HashSet::new();
+        for inc in &self.manifest.include {
+            if !self.enabled_features.contains(&inc.id) {
+                continue;
+            }
+            let inc_zip_name = inc.id.clone() + ".zip";
+            let name = inc.name.clone().unwrap_or_else(|| inc.location.clone());
+            match included_files.get(&inc_zip_name) {
+                None => changes.added.push(name),
+                Some(included) if inc.hash.as_ref().is_some_and(|hash| *hash != included.md5) => {
+                    changes.updated.push(name)
+                }
+                Some(_) => {}
+            }
+            kept.insert(inc_zip_name);
+        }
+        for inc in self.manifest.remote_include.iter().flatten() {
+            if !self.enabled_features.contains(&inc.id) {
+                continue;
+            }
+            let name = inc.name.clone().unwrap_or_else(|| inc.location.clone());
+            match included_files.get(&inc.location) {
+                None => changes.added.push(name),
+                Some(included) if included.md5 != inc.version => changes.updated.push(name),
+                Some(_) => {}
+            }
+            kept.insert(inc.location.clone());
+        }
+        let mut removed: Vec<&String> = included_files.keys().filter(|x| !kept.contains(*x)).collect();
+        removed.sort();
+        for key in removed {
+            let name = local_manifest
+                .include
+                .iter()
+                .find(|x| x.id.clone() + ".zip" == *key)
+                .and_then(|x| x.name.clone())
+                .or_else(|| {
+                    local_manifest
+                        .remote_include
+                        .iter()
+                        .flatten()
+                        .find(|x| x.location == *key)
+                        .and_then(|x| x.name.clone())
+                })
+                .unwrap_or_else(|| key.clone());
+            changes.removed.push(name);
+        }
+        Some(changes)
+    }
+
+    async fn emit(&self, event: InstallEvent) {
+        if let Some(events) = &self.events {
+            // The receiver being dropped just means nobody is listening anymore
+            let _ = events.send(event).await;
+        }
+    }
+}
+
+pub async fn init(
+    modpack_source: String,
+    modpack_branch: String,
+    launcher: Launcher,
+) -> Result<InstallerProfile, InstallError> {
+    init_shared(modpack_source, modpack_branch, launcher, &CachedHttpClient::new()).await
+}
+
+/// Same as 'init' but fetches with 'http_client', the GUI passes the same client for every branch so they share its
+/// connection pool. The profile gets its own install state, see 'CachedHttpClient::share'.
+async fn init_shared(
+    modpack_source: String,
+    modpack_branch: String,
+    launcher: Launcher,
+    http_client: &CachedHttpClient,
+) -> Result<InstallerProfile, InstallError> {
+    let manifest = fetch_manifest(&modpack_source, &modpack_branch, http_client).await?;
+    build_profile(
+        manifest,
+        modpack_source,
+        modpack_branch,
+        Some(launcher),
+        http_client.share(),
+    )
+}
+
+/// Returns whether the modpack from 'modpack_source' and 'modpack_branch' is installed and which version and features.
+/// Only the local manifest is read, the newest manifest is fetched to fill 'update_available' if 'check_update' is set.
+pub async fn pack_status(
+    launcher: &Launcher,
+    modpack_source: &str,
+    modpack_branch: &str,
+    check_update: bool,
+) -> Result<PackStatus, InstallError> {
+    // The uuid is only known from the manifest, so the installed modpack is found by the source it was installed from
+    let source = format!("{modpack_source}{modpack_branch}");
+    let manifest_paths = match get_installed_manifest_paths(launcher) {
+        Ok(v) => v,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => vec![],
+        Err(e) => return Err(InstallError::Io(format!("Failed to list installed modpacks: {e}"))),
+    };
+    let local_manifest = manifest_paths.iter().find_map(|path| {
+        let manifest: Manifest = parse_json(&fs::read_to_string(path).ok()?, "manifest").ok()?;
+        if manifest.source.as_deref() == Some(source.as_str()) {
+            Some(Ok(manifest))
+        } else {
+            None
+        }
+    });
+    let latest_version = if check_update && local_manifest.is_some() {
+        Some(
+            fetch_manifest(modpack_source, modpack_branch, &CachedHttpClient::new())
+                .await?
+                .modpack_version,
+        )
+    } else {
+        None
+    };
+    Ok(PackStatus::new(local_manifest.as_ref(), latest_version.as_deref()))
+}
+
+/// Like 'init' but without a launcher, used to browse the contents of a modpack before a launcher is chosen.
+/// Nothing is read from or written to disk. Only the manifest is returned so a preview can't be installed.
+pub async fn init_preview(
+    modpack_source: String,
+    modpack_branch: String,
+) -> Result<Manifest, InstallError> {
+    let http_client = CachedHttpClient::new();
+    let manifest = fetch_manifest(&modpack_source, &modpack_branch, &http_client).await?;
+    // Checks the manifest the same way as for an install
+    build_profile(
+        manifest,
+        modpack_source,
+        modpack_branch,
+        None,
+        http_client,
+    )
+    .map(|x| x.manifest)
+}
+
+/// Returns the tags of 'modpack_source's releases which pin a 'modpack_version', newest first.
+/// Maintainers tag releases with the 'modpack_version', optionally prefixed with 'v'.
+async fn fetch_version_tags(
+    modpack_source: &str,
+    http_client: &CachedHttpClient,
+) -> Result<Vec<String>, InstallError> {
+    let url = http_client.endpoints.github_api.clone() + modpack_source + "releases";
+    let mut resp = match http_client.get_async(url).await {
+        Ok(v) => v,
+        Err(e) => return Err(InstallError::Network(format!("Failed to retrieve releases: {e}"))),
+    };
+    if resp.status() != StatusCode::OK {
+        return Err(InstallError::Network(format!(
+            "Encountered '{}' error code when retrieving releases",
+            resp.status().as_u16()
+        )));
+    }
+    let releases: Vec<GithubRelease> =
+        match parse_json(&resp.text().await.unwrap_or_default(), "releases") {
+            Ok(v) => v,
+            Err(e) => return Err(InstallError::Parse(format!("Failed to parse releases: {e}"))),
+        };
+    Ok(releases
+        .into_iter()
+        .map(|x| x.tag_name)
+        .filter(|x| x.trim_start_matches('v').starts_with(|c: char| c.is_ascii_digit()))
+        .collect())
+}
+
+/// Returns the 'modpack_version's which can be installed with 'init_pinned', newest first
+async fn fetch_pinned_versions(
+    modpack_source: String,
+    http_client: CachedHttpClient,
+) -> Result<Vec<String>, InstallError> {
+    Ok(fetch_version_tags(&modpack_source, &http_client)
+        .await?
+        .into_iter()
+        .map(|x| x.trim_start_matches('v').to_string())
+        .collect())
+}
+
+/// Same as 'init' but for the manifest of the release tagged with 'modpack_version' instead of the newest one.
+/// Its includes are taken from that release as well, installing it over a newer version rolls the modpack back.
+async fn init_pinned(
+    modpack_source: String,
+    modpack_version: String,
+    launcher: Launcher,
+    http_client: &CachedHttpClient,
+) -> Result<InstallerProfile, InstallError> {
+    let tag = match fetch_version_tags(&modpack_source, http_client)
+        .await?
+        .into_iter()
+        .find(|x| x.trim_start_matches('v') == modpack_version)
+    {
+        Some(v) => v,
+        None => {
+            return Err(InstallError::Other(format!(
+                "No release found for version '{modpack_version}'"
+            )))
+        }
+    };
+    let manifest = fetch_manifest(&modpack_source, &tag, http_client).await?;
+    if manifest.modpack_version != modpack_version {
+        warn!(
+            "Release '{tag}' contains version '{}' instead of '{modpack_version}'",
+            manifest.modpack_version
+        );
+    }
+    build_profile(
+        manifest,
+        modpack_source,
+        tag,
+        Some(launcher),
+        http_client.share(),
+    )
+}
+
+/// Merges 'child' into 'base', fields of the child replace the ones of the base except for the 'MERGED_LISTS'.
+/// Those are concatenated with the base's entries first, a child entry with the same key replaces the base's entry in place.
+fn merge_manifests(base: JsonValue, child: JsonValue) -> JsonValue {
+    let mut merged = match base {
+        JsonValue::Object(v) => v,
+        _ => return child,
+    };
+    let child = match child {
+        JsonValue::Object(v) => v,
+        v => return v,
+    };
+    for (key, value) in child {
+        let id_key = MERGED_LISTS.iter().find(|(x, _)| *x == key).map(|(_, x)| *x);
+        match (id_key, merged.remove(&key), value) {
+            (Some(id_key), Some(JsonValue::Array(mut items)), JsonValue::Array(child_items)) => {
+                for item in child_items {
+                    let existing = items
+                        .iter()
+                        .position(|x| x.get(id_key).is_some() && x.get(id_key) == item.get(id_key));
+                    match existing {
+                        Some(i) => items[i] = item,
+                        None => items.push(item),
+                    }
+                }
+                merged.insert(key, JsonValue::Array(items));
+            }
+            (_, _, value) => {
+                merged.insert(key, value);
+            }
+        }
+    }
+    JsonValue::Object(merged)
+}
+
+/// Resolves the 'extends' of a manifest at 'location'. Absolute urls are used as is, anything else is relative to
+/// the directory of 'location' so the base is taken from the same revision, e.g. the release a pinned version is installed from.
+fn resolve_extends_location(location: &str, extends: &str) -> String {
+    if extends.starts_with("http://") || extends.starts_with("https://") {
+        return extends.to_string();
+    }
+    let mut parts: Vec<&str> = location.split('/').collect();
+    parts.pop(); // the filename of the extending manifest
+    for part in extends.split('/') {
+        match part {
+            "." | "" => {}
+            ".." => {
+                parts.pop();
+            }
+            part => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+/// Fetches the base manifests 'manifest' at 'location' 'extends' (recursively) and merges them, see 'merge_manifests'.
+/// Bases are read from disk if 'location' is a path. 'refresh' drops cached bases first so changes to any manifest
+/// in the chain are picked up.
+async fn resolve_extends(
+    manifest: JsonValue,
+    location: &str,
+    refresh: bool,
+    http_client: &CachedHttpClient,
+) -> Result<JsonValue, InstallError> {
+    let mut chain = vec![manifest];
+    let mut location = location.to_string();
+    while let Some(extends) = chain
+        .last()
+        .and_then(|x| x.get("extends"))
+        .and_then(|x| x.as_str())
+        .map(String::from)
+    {
+        if chain.len() > MAX_EXTENDS_DEPTH {
+            return Err(InstallError::InvalidManifest(format!(
+                "More than {MAX_EXTENDS_DEPTH} manifests extend each other, do they extend themselves?"
+            )));
+        }
+        let url = resolve_extends_location(&location, &extends);
+        let contents = if url.starts_with("http://") || url.starts_with("https://") {
+            if refresh {
+                http_client.invalidate(url.clone()).await;
+            }
+            let mut resp = match http_client.get_async(url.clone()).await {
+                Ok(v) => v,
+                Err(e) => {
+                    return Err(InstallError::Network(format!(
+                        "Failed to retrieve base manifest '{url}': {e}"
+                    )))
+                }
+            };
+            if resp.status() != StatusCode::OK {
+                http_client.invalidate(url.clone()).await;
+                return Err(InstallError::Network(format!(
+                    "Encountered '{}' error code when retrieving base manifest '{url}'",
+                    resp.status().as_u16()
+                )));
+            }
+            resp.text().await.unwrap_or_default()
+        } else {
+            match fs::read_to_string(&url) {
+                Ok(v) => v,
+                Err(e) => return Err(InstallError::Io(format!("Failed to read base manifest '{url}': {e}"))),
+            }
+        };
+        chain.push(parse_json(&contents, "extends").map_err(InstallError::Parse)?);
+        location = url;
+    }
+    let mut merged = chain.pop().unwrap(); // 'chain' always contains 'manifest'
+    while let Some(child) = chain.pop() {
+        merged = merge_manifests(merged, child);
+    }
+    Ok(merged)
+}
+
+async fn fetch_manifest(
+    modpack_source: &str,
+    modpack_branch: &str,
+    http_client: &CachedHttpClient,
+) -> Result<Manifest, InstallError> {
+    let manifest_url =
+        http_client.endpoints.github_raw.clone() + modpack_source + modpack_branch + "/manifest.json";
+    let mut manifest_resp = match http_client.get_async(manifest_url.clone()).await {
+        Ok(val) => val,
+        Err(e) => return Err(e.into()),
+    };
+    // 'modpack_branch' can be a branch, tag or commit sha as raw.githubusercontent.com resolves all of them
+    match manifest_resp.status() {
+        StatusCode::OK => (),
+        StatusCode::NOT_FOUND => {
+            // Don't keep serving the error response from the cache, the branch may be pushed later
+            http_client.invalidate(manifest_url).await;
+            return Err(InstallError::Other(format!(
+                "Branch/tag '{modpack_branch}' not found in '{modpack_source}' or it has no 'manifest.json'!"
+            )));
+        }
+        status => {
+            // Don't keep serving the error response from the cache
+            http_client.invalidate(manifest_url).await;
+            return Err(InstallError::Network(format!(
+                "Encountered '{}' error code when retrieving manifest for '{modpack_branch}'!",
+                status.as_u16()
+            )));
+        }
+    }
+    let manifest: JsonValue = match parse_json(manifest_resp.text().await.unwrap().as_str(), "manifest") {
+        Ok(val) => val,
+        Err(e) => {
+            // The cached body may be a transient error page, so drop it and try once more without the cache
+            warn!("Failed to parse manifest for '{modpack_branch}', refetching it: {e}");
+            http_client.invalidate(manifest_url.clone()).await;
+            let mut resp = match http_client.get_nocache(manifest_url.clone()).await {
+                Ok(val) => val,
+                Err(e) => return Err(InstallError::Network(e.to_string())),
+            };
+            let text = match resp.text().await {
+                Ok(val) => val,
+                Err(e) => return Err(InstallError::Network(e.to_string())),
+            };
+            match parse_json(&text, "manifest") {
+                Ok(val) => val,
+                Err(e) => return Err(InstallError::Parse(e)),
+            }
+        }
+    };
+    let manifest = resolve_extends(manifest, &manifest_url, false, http_client).await?;
+    match parse_json_value(manifest, "manifest") {
+        Ok(val) => Ok(val),
+        Err(e) => Err(InstallError::Parse(e)),
+    }
+}
+
+/// Fetches the 'modpack_version' of the remote manifest. Unlike 'init' this is attempted only once as it's polled.
+async fn fetch_remote_version(installer_profile: &InstallerProfile) -> Result<String, InstallError> {
+    let url = installer_profile.http_client.endpoints.github_raw.clone()
+        + &installer_profile.modpack_source
+        + &installer_profile.modpack_branch
+        + "/manifest.json";
+    let http_client = &installer_profile.http_client;
+    // Cached responses never expire so drop it, the fresh response is cached again for the next 'init'
+    http_client.invalidate(url.clone()).await;
+    let cached = get_cached(&http_client.http_client, url.clone()).await?;
+    if cached.resp.status() != StatusCode::OK {
+        http_client.invalidate(url).await;
+        return Err(InstallError::Network(format!(
+            "Encountered '{}' error code when retrieving manifest",
+            cached.resp.status().as_u16()
+        )));
+    }
+    match serde_json::from_slice::<JsonValue>(&cached.bytes) {
+        // The version may be set by a base manifest only
+        Ok(v) => match resolve_extends(v, &url, true, http_client).await?
+            .get("modpack_version")
+            .and_then(|v| v.as_str())
+        {
+            Some(version) => Ok(version.to_string()),
+            None => Err(InstallError::InvalidManifest(String::from(
+                "Manifest is missing 'modpack_version'",
+            ))),
+        },
+        Err(e) => {
+            http_client.invalidate(url).await;
+            Err(InstallError::Parse(e.to_string()))
+        }
+    }
+}
+
+/// Builds an installer profile for an already retrieved manifest, used by 'init' after fetching it
+pub fn init_from_manifest(
+    manifest: Manifest,
+    modpack_source: String,
+    modpack_branch: String,
+    launcher: Launcher,
+) -> Result<InstallerProfile, InstallError> {
+    build_profile(
+        manifest,
+        modpack_source,
+        modpack_branch,
+        Some(launcher),
+        CachedHttpClient::new(),
+    )
+}
+
+fn build_profile(
+    manifest: Manifest,
+    modpack_source: String,
+    modpack_branch: String,
+    launcher: Option<Launcher>,
+    http_client: CachedHttpClient,
+) -> Result<InstallerProfile, InstallError> {
+    if let Some(min_version) = &manifest.min_installer_version {
+        if is_older_version(env!("CARGO_PKG_VERSION"), min_version) {
+            return Err(InstallError::InstallerOutdated(
+                manifest.subtitle.clone(),
+                min_version.clone(),
+            ));
+        }
+    }
+    // Its not guaranteed that a manifest with a different version manages to parse however we handle parsing failures and therefore we should be fine to just return an error here
+    if CURRENT_MANIFEST_VERSION != manifest.manifest_version {
+        return Err(InstallError::UnsupportedManifestVersion(manifest.manifest_version));
+    }
+    let fatal = fatal_manifest_problems(&manifest);
+    if !fatal.is_empty() {
+        return Err(InstallError::InvalidManifest(format!(
+            "Invalid manifest '{}':\n- {}",
+            manifest.subtitle,
+            fatal.join("\n- ")
+        )));
+    }
+    // The rest is only warned about so manifests which installed fine before keep working, 'lint_manifest' reports them
+    if let Err(e) = validate_manifest(&manifest) {
+        warn!("{e}");
+    }
+    // Previews don't have a launcher so there is no installed modpack to look at
+    let local_manifest: Option<Result<Manifest, String>> = match &launcher {
+        Some(launcher) => {
+            let modpack_root = get_modpack_root(launcher, &manifest.uuid);
+            if modpack_root.join(Path::new("manifest.json")).exists() {
+                let local_manifest_content =
+                    match fs::read_to_string(modpack_root.join(Path::new("manifest.json"))) {
+                        Ok(val) => val,
+                        Err(e) => return Err(InstallError::Io(e.to_string())),
+                    };
+                Some(parse_json(&local_manifest_content, "manifest"))
+            } else {
+                None
+            }
+        }
+        None => None,
+    };
+    let status = PackStatus::new(local_manifest.as_ref(), Some(&manifest.modpack_version));
+    let installed = status.installed;
+    let update_available = status.update_available.unwrap_or(false);
+    let mut enabled_features = vec![default_id()];
+    // An installed modpack keeps the features it was installed with until they're changed
+    if let Some(Ok(local_manifest)) = &local_manifest {
+        for feat in &local_manifest.enabled_features {
+            if !enabled_features.contains(feat) {
+                enabled_features.push(feat.clone());
+            }
+        }
+    }
+    if !installed {
+        for feat in &manifest.features {
+            if feat.default && feat.supports_current_os() {
+                enabled_features.push(feat.id.clone());
+            }
+        }
+        for inc in &manifest.include {
+            if inc.default && !enabled_features.contains(&inc.id) {
+                enabled_features.push(inc.id.clone());
+            }
+        }
+    }
+    Ok(InstallerProfile {
+        manifest,
+        http_client,
+        installed,
+        update_available,
+        modpack_source,
+        modpack_branch,
+        enabled_features,
+        launcher,
+        cancelled: Arc::new(AtomicBool::new(false)),
+        target_side: Side::Client,
+        force_loader: false,
+        events: None,
+        file_system: Arc::new(RealFileSystem),
+        local_manifest: if local_manifest.is_some() && local_manifest.as_ref().unwrap().is_ok() {
+            Some(local_manifest.unwrap().unwrap())
+        } else {
+            None
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::ErrorKind;
+
+    #[derive(Debug, Default)]
+    struct MemoryFileSystem {
+        files: Mutex<HashMap<PathBuf, Vec<u8>>>,
+    }
+
+    impl MemoryFileSystem {
+        fn with_files(paths: &[&str]) -> MemoryFileSystem {
+            let file_system = MemoryFileSystem::default();
+            for path in paths {
+                file_system.write(Path::new(path), path.as_bytes()).unwrap();
+            }
+            file_system
+        }
+
+        fn exists(&self, path: &str) -> bool {
+            self.files.lock().unwrap().contains_key(Path::new(path))
+        }
+    }
+
+    impl FileSystem for MemoryFileSystem {
+        fn read(&self, path: &Path) -> Result<Vec<u8>, std::io::Error> {
+            match self.files.lock().unwrap().get(path) {
+                Some(v) => Ok(v.clone()),
+                None => Err(ErrorKind::NotFound.into()),
+            }
+        }
+
+        fn write(&self, path: &Path, contents: &[u8]) -> Result<(), std::io::Error> {
+            self.files
+                .lock()
+                .unwrap()
+                .insert(path.to_path_buf(), contents.to_vec());
+            Ok(())
+        }
+
+        fn remove_file(&self, path: &Path) -> Result<(), std::io::Error> {
+            match self.files.lock().unwrap().remove(path) {
+                Some(_) => Ok(()),
+                None => Err(ErrorKind::NotFound.into()),
+            }
+        }
+
+        fn rename(&self, from: &Path, to: &Path) -> Result<(), std::io::Error> {
+            let mut files = self.files.lock().unwrap();
+            match files.remove(from) {
+                Some(v) => {
+                    files.insert(to.to_path_buf(), v);
+                    Ok(())
+                }
+                None => Err(ErrorKind::NotFound.into()),
+            }
+        }
+    }
+
+    fn test_mod(name: &str, version: &str, id: &str, path: Option<&str>) -> Mod {
+        serde_json::from_value(json!({
+            "name": name,
+            "source": "ddl",
+            "location": format!("http://localhost/{name}.jar"),
+            "version": version,
+            "path": path,
+            "id": id,
+            "authors": [],
+        }))
+        .unwrap()
+    }
+
+    fn test_profile(mods: Vec<Mod>, file_system: Arc<MemoryFileSystem>) -> InstallerProfile {
+        let manifest: Manifest = serde_json::from_value(json!({
+            "manifest_version": CURRENT_MANIFEST_VERSION,
+            "modpack_version": "1.0.0",
+            "name": "Test pack",
+            "subtitle": "Test",
+            "description": "",
+            "icon": false,
+            "uuid": "unit-test",
+            "loader": { "type": "fabric", "version": "0.15.11", "minecraft_version": "1.20.4" },
+            "mods": mods,
+            "shaderpacks": [],
+            "resourcepacks": [],
+            "include": [],
+            "features": [{ "id": "extra", "name": "Extra", "default": false }],
+        }))
+        .unwrap();
+        let mut profile = build_profile(
+            manifest,
+            String::from("Owner/repo/"),
+            String::from("main"),
+            None,
+            CachedHttpClient::new(),
+        )
+        .unwrap();
+        profile.file_system = file_system;
+        profile
+    }
+
+    #[test]
+    fn remove_old_items_removes_changed_and_dropped_items() {
+        let file_system = MemoryFileSystem::with_files(&[
+            "/root/mods/a.jar",
+            "/root/mods/b.jar",
+            "/root/mods/kept.jar",
+        ]);
+        let installed = vec![
+            test_mod("A", "1", "default", Some("/root/mods/a.jar")),
+            test_mod("B", "1", "default", Some("/root/mods/b.jar")),
+            test_mod("Kept", "1", "default", Some("/root/mods/kept.jar")),
+        ];
+        let items = vec![
+            test_mod("A", "2", "default", None),
+            test_mod("Kept", "1", "default", None),
+        ];
+
+        let new_items = remove_old_items(&items, &installed, &file_system);
+
+        assert!(!file_system.exists("/root/mods/a.jar"));
+        assert!(!file_system.exists("/root/mods/b.jar"));
+        assert!(file_system.exists("/root/mods/kept.jar"));
+        assert_eq!(new_items[0].path, None);
+        assert_eq!(
+            new_items[1].path,
+            Some(PathBuf::from("/root/mods/kept.jar"))
+        );
+    }
+
+    #[test]
+    fn toggling_a_feature_renames_its_items() {
+        let file_system = Arc::new(MemoryFileSystem::with_files(&["/root/mods/b.jar"]));
+        let mods = vec![test_mod("B", "1", "extra", Some("/root/mods/b.jar"))];
+        let mut profile = test_profile(mods.clone(), file_system.clone());
+
+        let disabled =
+            futures::executor::block_on(download_helper(mods, &profile, Path::new("/root"), || {}))
+                .unwrap()
+                .0;
+        assert!(file_system.exists("/root/mods/b.jar.disabled"));
+        assert_eq!(
+            disabled[0].path,
+            Some(PathBuf::from("/root/mods/b.jar.disabled"))
+        );
+
+        profile.enabled_features.push(String::from("extra"));
+        let enabled = futures::executor::block_on(download_helper(
+            disabled,
+            &profile,
+            Path::new("/root"),
+            || {},
+        ))
+        .unwrap()
+        .0;
+        assert!(file_system.exists("/root/mods/b.jar"));
+        assert_eq!(enabled[0].path, Some(PathBuf::from("/root/mods/b.jar")));
+    }
+
+    #[test]
+    fn nested_item_paths_are_valid() {
+        let item = test_mod("A", "1", "default", Some("/root/mods/nested/deeper/a.jar"));
+        let item = validate_item_path(item, Path::new("/root")).unwrap();
+        assert_eq!(
+            item.path,
+            Some(PathBuf::from("/root/mods/nested/deeper/a.jar"))
+        );
+    }
+
+    #[test]
+    fn escaping_item_paths_are_invalid() {
+        let item = test_mod("A", "1", "default", Some("/root/mods/../../a.jar"));
+        assert!(matches!(
+            validate_item_path(item, Path::new("/root")),
+            Err(DownloadError::InvalidPath(_))
+        ));
+    }
+
+    #[test]
+    fn modpack_sources_are_normalized() {
+        for input in [
+            "Owner/repo",
+            "Owner/repo/",
+            " https://github.com/Owner/repo ",
+            "github.com/Owner/repo.git",
+        ] {
+            assert_eq!(parse_modpack_source(input), Ok(String::from("Owner/repo/")));
+        }
+        assert_eq!(parse_modpack_source(""), Ok(String::from(REPO)));
+        assert!(parse_modpack_source("Owner").is_err());
+        assert!(parse_modpack_source("Owner/repo/tree/main").is_err());
+        assert!(parse_modpack_source("../repo").is_err());
+    }
+
+    #[test]
+    fn cfg_values_are_replaced_or_appended() {
+        let contents = "InstanceType=OneSix\nMaxMemAlloc=4096\nJvmArgs=-Dold";
+        let values = [
+            ("MaxMemAlloc", String::from("8192")),
+            ("OverrideMemory", String::from("true")),
+        ];
+        assert_eq!(
+            set_cfg_values(contents, &values),
+            "InstanceType=OneSix\nMaxMemAlloc=8192\nJvmArgs=-Dold\nOverrideMemory=true"
+        );
+    }
+
+    #[test]
+    fn large_icons_are_downscaled() {
+        let img = DynamicImage::new_rgba8(1024, 512);
+        let data_uri = image_to_base64(&img);
+        let png = engine::general_purpose::STANDARD
+            .decode(data_uri.trim_start_matches("data:image/png;base64,"))
+            .unwrap();
+        let icon = image::load_from_memory(&png).unwrap();
+        assert_eq!((icon.width(), icon.height()), (MAX_ICON_SIZE, MAX_ICON_SIZE / 2));
+    }
+
+    #[test]
+    fn child_manifest_entries_override_base_entries() {
+        let base = json!({
+            "name": "Base",
+            "subtitle": "Base",
+            "mods": [{ "name": "A", "version": "1" }, { "name": "B", "version": "1" }],
+            "features": [{ "id": "extra", "name": "Extra" }],
+        });
+        let child = json!({
+            "subtitle": "Child",
+            "mods": [{ "name": "B", "version": "2" }, { "name": "C", "version": "1" }],
+        });
+        let merged = merge_manifests(base, child);
+        assert_eq!(merged["name"], "Base");
+        assert_eq!(merged["subtitle"], "Child");
+        assert_eq!(
+            merged["mods"],
+            json!([
+                { "name": "A", "version": "1" },
+                { "name": "B", "version": "2" },
+                { "name": "C", "version": "1" },
+            ])
+        );
+        assert_eq!(merged["features"], json!([{ "id": "extra", "name": "Extra" }]));
+    }
+
+    #[test]
+    fn content_disposition_filenames_are_parsed() {
+        let cases = [
+            (r#"attachment; filename="Mod.jar""#, Some("Mod.jar")),
+            ("attachment; filename=Mod.jar", Some("Mod.jar")),
+            (r#"attachment; filename="My; \"Mod\".jar""#, Some(r#"My; "Mod".jar"#)),
+            ("attachment; filename*=UTF-8''M%C3%B6d%20%E2%9C%93.jar", Some("Möd ✓.jar")),
+            ("attachment; FILENAME*=iso-8859-1'en'M%F6d.jar", Some("Möd.jar")),
+            (
+                r#"attachment; filename="Mod.jar"; filename*=UTF-8''M%C3%B6d.jar"#,
+                Some("Möd.jar"),
+            ),
+            (
+                r#"attachment; filename*=unknown''M%F6d.jar; filename="Mod.jar""#,
+                Some("Mod.jar"),
+            ),
+            ("attachment; filename*=UTF-8''..%2F..%2Fevil.jar", Some(".._.._evil.jar")),
+            ("attachment", None),
+            (r#"attachment; filename="""#, None),
+            ("attachment; filename=..", None),
+        ];
+        for (header, expected) in cases {
+            assert_eq!(
+                parse_content_disposition(header).as_deref(),
+                expected,
+                "{header}"
+            );
+        }
+    }
+
+    #[test]
+    fn managed_file_globs_match() {
+        let cases = [
+            ("config/mymod/*.json", "config/mymod/a.json", true),
+            ("config/mymod/*.json", "config/mymod/sub/a.json", false),
+            ("config/**/*.json", "config/a.json", true),
+            ("config/**/*.json", "config/mymod/sub/a.json", true),
+            ("shaderpacks/pack/**", "shaderpacks/pack/shaders.txt", true),
+            ("options?.txt", "options1.txt", true),
+            ("options?.txt", "options.txt", false),
+            ("mods/a+b.jar", "mods/a+b.jar", true),
+            ("mods/a+b.jar", "mods/aab.jar", false),
+        ];
+        for (pattern, path, expected) in cases {
+            assert_eq!(glob_to_regex(pattern).is_match(path), expected, "{pattern} {path}");
+        }
+    }
+
+    #[test]
+    fn memory_is_clamped() {
+        assert_eq!(clamp_memory(Some(4096), Some(2048), Some(16384)), (Some(4096), Some(2048), vec![]));
+        assert_eq!(clamp_memory(None, None, None), (None, None, vec![]));
+        // Typo of 2048
+        let (max_mem, min_mem, adjustments) = clamp_memory(Some(20480), Some(2048), Some(16384));
+        assert_eq!((max_mem, min_mem, adjustments.len()), (Some(12288), Some(2048), 1));
+        let (max_mem, min_mem, adjustments) = clamp_memory(Some(2048), Some(4096), None);
+        assert_eq!((max_mem, min_mem, adjustments.len()), (Some(2048), Some(2048), 1));
+        let (max_mem, min_mem, adjustments) = clamp_memory(Some(8192), Some(8192), Some(4096));
+        assert_eq!((max_mem, min_mem, adjustments.len()), (Some(3072), Some(3072), 2));
+    }
+
+    #[test]
+    fn user_data_survives_removing_the_modpack_root() {
+        let root = env::temp_dir().join(format!("wc_ovhl_user_data_{}", std::process::id()));
+        let modpack_root = root.join("pack");
+        let user_data_dir = root.join("pack-data");
+        fs::create_dir_all(modpack_root.join("saves/world")).unwrap();
+        link_user_data(&modpack_root, &user_data_dir).unwrap();
+        assert!(user_data_dir.join("saves/world").is_dir());
+        fs::write(modpack_root.join("screenshots/a.png"), "a").unwrap();
+
+        unlink_user_data(&modpack_root);
+        fs::remove_dir_all(&modpack_root).unwrap();
+        assert!(user_data_dir.join("saves/world").is_dir());
+        assert!(user_data_dir.join("screenshots/a.png").exists());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn host_auth_is_redacted_in_logs() {
+        let config: HashMap<String, HostAuth> =
+            serde_json::from_value(json!({ "cdn.example.com": { "value": "Bearer secret" } })).unwrap();
+        let auth = &config["cdn.example.com"];
+        assert_eq!(auth.header, "Authorization");
+        assert!(!format!("{config:?}").contains("secret"));
+    }
+
+    #[test]
+    fn manifest_html_is_sanitized_for_untrusted_sources() {
+        let mut profile = test_profile(vec![], Arc::new(MemoryFileSystem::default()));
+        let html = r#"<p onclick="alert(1)">Hi</p><script>alert(2)</script>"#;
+        assert_eq!(profile.manifest_html(html), "<p>Hi</p>");
+        // 'raw_html' alone doesn't make a source trusted
+        profile.manifest.raw_html = true;
+        assert_eq!(profile.manifest_html(html), "<p>Hi</p>");
+        profile.modpack_source = String::from(REPO);
+        assert_eq!(profile.manifest_html(html), html);
+    }
+
+    #[test]
+    fn update_changes_lists_changed_items_of_enabled_features() {
+        let file_system = Arc::new(MemoryFileSystem::with_files(&[]));
+        let mut profile = test_profile(
+            vec![
+                test_mod("A", "2", "default", None),
+                test_mod("Kept", "1", "default", None),
+                test_mod("New", "1", "default", None),
+                test_mod("Optional", "1", "extra", None),
+            ],
+            file_system,
+        );
+        let mut local_manifest = profile.manifest.clone();
+        local_manifest.mods = vec![
+            test_mod("A", "1", "default", Some("/root/mods/a.jar")),
+            test_mod("Kept", "1", "default", Some("/root/mods/kept.jar")),
+            test_mod("Gone", "1", "default", Some("/root/mods/gone.jar")),
+        ];
+        local_manifest.enabled_features = vec![String::from("default")];
+        local_manifest.included_files = Some(HashMap::from([(
+            String::from("old.zip"),
+            Included::new(String::from("hash"), vec![]),
+        )]));
+        assert_eq!(profile.update_changes(), None);
+        profile.local_manifest = Some(local_manifest);
+
+        let changes = profile.update_changes().unwrap();
+
+        assert_eq!(changes.added, vec![String::from("New")]);
+        assert_eq!(changes.updated, vec![String::from("A")]);
+        assert_eq!(changes.removed, vec![String::from("Gone"), String::from("old.zip")]);
+    }
+
+    #[test]
+    fn redirect_limit_prefers_the_host_limit() {
+        let mut config: Config = serde_json::from_value(json!({ "launcher": "vanilla" })).unwrap();
+        assert_eq!(redirect_limit(Some(&config), None), DEFAULT_MAX_REDIRECTS);
+
+        config.max_redirects = Some(3);
+        config.redirect_limits = Some(HashMap::from([(String::from("Mirror.example.com"), 20)]));
+        assert_eq!(redirect_limit(Some(&config), Some("mirror.example.com")), 20);
+        assert_eq!(redirect_limit(Some(&config), Some("cdn.modrinth.com")), 3);
+        assert_eq!(redirect_limit(None, Some("mirror.example.com")), DEFAULT_MAX_REDIRECTS);
+    }
+
+    #[test]
+    fn incomplete_launcher_profiles_fail_verification() {
+        let root = env::temp_dir().join(format!("wc_ovhl_launcher_profile_{}", std::process::id()));
+        let instance = root.join("instances/unit-test");
+        fs::create_dir_all(&instance).unwrap();
+        let launcher = Launcher::MultiMC(root.clone());
+        let modpack_root = get_modpack_root(&launcher, "unit-test");
+        fs::write(instance.join("mmc-pack.json"), r#"{"components":[],"formatVersion":1}"#).unwrap();
+        fs::write(instance.join("instance.cfg"), "InstanceType=OneSix\niconKey=unit-test").unwrap();
+        assert!(matches!(
+            verify_launcher_profile(&launcher, "unit-test", &modpack_root),
+            Err(LauncherProfileError::NotCreated(_))
+        ));
+
+        fs::write(instance.join("instance.cfg"), "InstanceType=OneSix\nname=Test pack").unwrap();
+        assert!(verify_launcher_profile(&launcher, "unit-test", &modpack_root).is_ok());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn managed_files_never_remove_items_or_user_data() {
+        for pattern in ["**", "*.json", "mods/**", "shaderpacks/*", "saves/**", "../x"] {
+            assert!(managed_files_pattern_problem(pattern).is_some(), "{pattern}");
+        }
+        assert_eq!(managed_files_pattern_problem("shaderpacks/pack.txt"), None);
+
+        let root = env::temp_dir().join(format!("wc_ovhl_managed_files_{}", std::process::id()));
+        fs::create_dir_all(root.join("config/mymod")).unwrap();
+        fs::write(root.join("config/mymod/a.json"), "a").unwrap();
+        fs::write(root.join("config/mymod/item.json"), "item").unwrap();
+        let item_paths = HashSet::from([root.join("config/mymod/item.json")]);
+        remove_managed_files(&root, &[String::from("config/**")], &item_paths);
+        assert!(!root.join("config/mymod/a.json").exists());
+        assert!(root.join("config/mymod/item.json").exists());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn branches_are_releases_without_configured_prefixes() {
+        let mut config: Config = serde_json::from_value(json!({ "launcher": "vanilla" })).unwrap();
+        assert!(is_release_branch(&config, "main"));
+
+        config.release_prefixes = Some(vec![String::from("release/")]);
+        assert!(is_release_branch(&config, "release/1.0"));
+        assert!(!is_release_branch(&config, "main"));
+    }
+
+    #[test]
+    fn relative_extends_resolve_against_the_extending_manifest() {
+        let location = "https://raw.githubusercontent.com/Owner/repo/v1.0.0/manifest.json";
+        assert_eq!(
+            resolve_extends_location(location, "base.json"),
+            "https://raw.githubusercontent.com/Owner/repo/v1.0.0/base.json"
+        );
+        assert_eq!(
+            resolve_extends_location(location, "../base/./manifest.json"),
+            "https://raw.githubusercontent.com/Owner/repo/base/manifest.json"
+        );
+        assert_eq!(
+            resolve_extends_location(location, "https://example.com/base.json"),
+            "https://example.com/base.json"
+        );
+        assert_eq!(resolve_extends_location("packs/manifest.json", "base.json"), "packs/base.json");
+    }
+
+    #[test]
+    fn pinned_hosts_follow_the_endpoints() {
+        let hosts = Endpoints::default().pinned_hosts();
+        for host in ["api.github.com", "raw.githubusercontent.com", "meta.quiltmc.org", "cdn.modrinth.com"] {
+            assert!(hosts.contains(&host.to_string()), "{host}");
+        }
+        let mirrored = Endpoints {
+            modrinth_api: String::from("https://modrinth.mirror.example/v2/"),
+            ..Endpoints::default()
+        };
+        let hosts = mirrored.pinned_hosts();
+        assert!(hosts.contains(&String::from("modrinth.mirror.example")));
+        assert!(!hosts.contains(&String::from("api.modrinth.com")));
+    }
+
+    #[test]
+    fn redirect_locations_resolve_against_the_request_url() {
+        let url = "https://mirror.example/files/mod.jar?token=1";
+        assert_eq!(
+            resolve_location(url, "https://cdn.example/mod.jar").as_deref(),
+            Some("https://cdn.example/mod.jar")
+        );
+        assert_eq!(
+            resolve_location(url, "//cdn.example/mod.jar").as_deref(),
+            Some("https://cdn.example/mod.jar")
+        );
+        assert_eq!(
+            resolve_location(url, "/other/mod.jar").as_deref(),
+            Some("https://mirror.example/other/mod.jar")
+        );
+        assert_eq!(
+            resolve_location(url, "../mod-2.jar").as_deref(),
+            Some("https://mirror.example/mod-2.jar")
+        );
+    }
+
+    #[test]
+    fn unreadable_local_manifests_are_skipped() {
+        let root = env::temp_dir().join(format!("wc_ovhl_installed_packs_{}", std::process::id()));
+        // A directory can't be read as a file
+        fs::create_dir_all(root.join(".WC_OVHL/broken/manifest.json")).unwrap();
+        let packs = get_installed_packs(&Launcher::Vanilla(root.clone()));
+        assert!(packs.is_ok_and(|x| x.is_empty()));
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,1062 @@
+// End to end tests for installing and updating a modpack against a local http server.
+// Every test gets its own sandbox directory set through 'WC_OVHL_ROOT'.
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{Read, Write},
+    net::TcpListener,
+    ops::Deref,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, MutexGuard,
+    },
+    thread,
+};
+
+use installer::{
+    export_pack, find_unmanaged_files, init, init_from_manifest, install, migrate, pack_status,
+    remove_unmanaged_files, update, InstallError, Launcher, Manifest, Side,
+};
+use serde_json::{json, Value as JsonValue};
+
+const LOADER_VERSION: &str = "0.15.11";
+const MINECRAFT_VERSION: &str = "1.20.4";
+
+/// Serves 'routes' (path -> body) on a random local port and returns the base url
+fn serve(routes: HashMap<String, Vec<u8>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+            let mut buf = [0; 4096];
+            let n = stream.read(&mut buf).unwrap_or(0);
+            let request = String::from_utf8_lossy(&buf[..n]);
+            // Modrinth filters versions through the query, every version is served regardless
+            let path = request
+                .split_whitespace()
+                .nth(1)
+                .and_then(|x| x.split('?').next())
+                .unwrap_or("/");
+            let (status, body) = match routes.get(path) {
+                Some(body) => ("200 OK", body.clone()),
+                None => ("404 Not Found", vec![]),
+            };
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                body.len()
+            );
+            let _ = stream.write_all(&body);
+        }
+    });
+    format!("http://{addr}")
+}
+
+/// Directory of a single test with a vanilla launcher layout, 'WC_OVHL_ROOT' points to it while it's alive
+struct Sandbox {
+    root: PathBuf,
+    /// Serves the loader json the sandbox already has installed, so updates checking it upstream find it unchanged
+    loader_meta: String,
+    // The env var is process wide, so tests using it run one after another
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Sandbox {
+    /// Writes 'config.json' with the loader meta and 'endpoints' pointing at local servers
+    fn config(&self, endpoints: JsonValue) {
+        let mut all_endpoints = json!({ "fabric_meta": format!("{}/", self.loader_meta) });
+        for (name, url) in endpoints.as_object().unwrap() {
+            all_endpoints[name] = url.clone();
+        }
+        let config_dir = self.root.join("Wynncraft Majestic Overhaul Installer");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("config.json"),
+            serde_json::to_vec(&json!({ "launcher": "vanilla", "endpoints": all_endpoints })).unwrap(),
+        )
+        .unwrap();
+    }
+}
+
+impl Deref for Sandbox {
+    type Target = PathBuf;
+
+    fn deref(&self) -> &PathBuf {
+        &self.root
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+fn sandbox() -> Sandbox {
+    static LOCK: Mutex<()> = Mutex::new(());
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    // A failed test poisons the lock, that doesn't affect the sandbox of the next one
+    let lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let root = env::temp_dir().join(format!(
+        "wc_ovhl_test_{}_{}",
+        std::process::id(),
+        COUNT.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = fs::remove_dir_all(&root);
+    let minecraft = root.join(".minecraft");
+    // Pretend the loader is already installed so the tests don't hit the fabric meta api
+    let loader_name = format!("fabric-loader-{LOADER_VERSION}-{MINECRAFT_VERSION}");
+    let loader_dir = minecraft.join("versions").join(&loader_name);
+    fs::create_dir_all(&loader_dir).unwrap();
+    fs::write(loader_dir.join(format!("{loader_name}.json")), "{}").unwrap();
+    fs::write(
+        minecraft.join("launcher_profiles.json"),
+        r#"{"profiles":{}}"#,
+    )
+    .unwrap();
+    env::set_var("WC_OVHL_ROOT", &root);
+    let loader_meta = serve(HashMap::from([(
+        format!("/versions/loader/{MINECRAFT_VERSION}/{LOADER_VERSION}/profile/json"),
+        b"{}".to_vec(),
+    )]));
+    let sandbox = Sandbox {
+        root,
+        loader_meta,
+        _lock: lock,
+    };
+    sandbox.config(json!({}));
+    sandbox
+}
+
+fn ddl_mod(name: &str, version: &str, location: String, id: &str) -> JsonValue {
+    json!({
+        "name": name,
+        "source": "ddl",
+        "location": location,
+        "version": version,
+        "id": id,
+        "authors": [],
+    })
+}
+
+fn manifest(uuid: &str, modpack_version: &str, mods: Vec<JsonValue>) -> Manifest {
+    serde_json::from_value(json!({
+        "manifest_version": 3,
+        "modpack_version": modpack_version,
+        "name": "Test pack",
+        "subtitle": "Test",
+        "description": "",
+        "icon": false,
+        "uuid": uuid,
+        "loader": {
+            "type": "fabric",
+            "version": LOADER_VERSION,
+            "minecraft_version": MINECRAFT_VERSION,
+        },
+        "mods": mods,
+        "shaderpacks": [],
+        "resourcepacks": [],
+        "include": [],
+        "features": [
+            { "id": "extra", "name": "Extra", "default": false },
+        ],
+    }))
+    .unwrap()
+}
+
+fn read_json(path: &Path) -> JsonValue {
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn install_writes_enabled_items_and_local_manifest() {
+    let root = sandbox();
+    let server = serve(HashMap::from([
+        (String::from("/a.jar"), b"mod a".to_vec()),
+        (String::from("/b.jar"), b"mod b".to_vec()),
+    ]));
+    let uuid = "install-test";
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.0",
+            vec![
+                ddl_mod("A", "1", format!("{server}/a.jar"), "default"),
+                ddl_mod("B", "1", format!("{server}/b.jar"), "extra"),
+            ],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    assert_eq!(fs::read(modpack_root.join("mods/a.jar")).unwrap(), b"mod a");
+    assert!(
+        !modpack_root.join("mods/b.jar").exists(),
+        "Items of disabled features should not be downloaded"
+    );
+
+    let local_manifest = read_json(&modpack_root.join("manifest.json"));
+    assert_eq!(local_manifest["modpack_version"], "1.0.0");
+    assert_eq!(local_manifest["enabled_features"], json!(["default"]));
+    let mods = local_manifest["mods"].as_array().unwrap();
+    let a = mods.iter().find(|x| x["name"] == "A").unwrap();
+    assert_eq!(
+        PathBuf::from(a["path"].as_str().unwrap()),
+        modpack_root.join("mods/a.jar")
+    );
+    let b = mods.iter().find(|x| x["name"] == "B").unwrap();
+    assert!(b["path"].is_null());
+
+    let profiles = read_json(&root.join(".minecraft/launcher_profiles.json"));
+    assert_eq!(profiles["profiles"][uuid]["name"], "Test pack");
+}
+
+#[test]
+fn update_replaces_changed_items() {
+    let root = sandbox();
+    let server = serve(HashMap::from([
+        (String::from("/old.jar"), b"old".to_vec()),
+        (String::from("/new.jar"), b"new".to_vec()),
+        (String::from("/kept.jar"), b"kept".to_vec()),
+    ]));
+    let uuid = "update-test";
+    let launcher = Launcher::Vanilla(root.clone());
+    let source = String::from("Owner/repo/");
+    let branch = String::from("main");
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.0",
+            vec![
+                ddl_mod("Changed", "1", format!("{server}/old.jar"), "default"),
+                ddl_mod("Kept", "1", format!("{server}/kept.jar"), "default"),
+            ],
+        ),
+        source.clone(),
+        branch.clone(),
+        launcher.clone(),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "2.0.0",
+            vec![
+                ddl_mod("Changed", "2", format!("{server}/new.jar"), "default"),
+                ddl_mod("Kept", "1", format!("{server}/kept.jar"), "default"),
+            ],
+        ),
+        source,
+        branch,
+        launcher,
+    )
+    .unwrap();
+    futures::executor::block_on(update(&profile, Side::Client, || {})).unwrap();
+
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    assert!(!modpack_root.join("mods/old.jar").exists());
+    assert_eq!(fs::read(modpack_root.join("mods/new.jar")).unwrap(), b"new");
+    assert_eq!(fs::read(modpack_root.join("mods/kept.jar")).unwrap(), b"kept");
+    let local_manifest = read_json(&modpack_root.join("manifest.json"));
+    assert_eq!(local_manifest["modpack_version"], "2.0.0");
+}
+
+/// Changes the enabled features of an installed pack the way the GUI persists them
+fn set_enabled_features(modpack_root: &Path, features: JsonValue) {
+    let path = modpack_root.join("manifest.json");
+    let mut local_manifest = read_json(&path);
+    local_manifest["enabled_features"] = features;
+    fs::write(path, local_manifest.to_string()).unwrap();
+}
+
+#[test]
+fn disabled_items_are_renamed_and_restored() {
+    let root = sandbox();
+    let server = serve(HashMap::from([(String::from("/b.jar"), b"mod b".to_vec())]));
+    let uuid = "disable-test";
+    let launcher = Launcher::Vanilla(root.clone());
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    let update_with = |features: JsonValue| {
+        set_enabled_features(&modpack_root, features);
+        let profile = init_from_manifest(
+            manifest(
+                uuid,
+                "1.0.0",
+                vec![ddl_mod("B", "1", format!("{server}/b.jar"), "extra")],
+            ),
+            String::from("Owner/repo/"),
+            String::from("main"),
+            launcher.clone(),
+        )
+        .unwrap();
+        futures::executor::block_on(update(&profile, Side::Client, || {})).unwrap();
+    };
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.0",
+            vec![ddl_mod("B", "1", format!("{server}/b.jar"), "extra")],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        launcher.clone(),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    update_with(json!(["default", "extra"]));
+    assert_eq!(fs::read(modpack_root.join("mods/b.jar")).unwrap(), b"mod b");
+
+    update_with(json!(["default"]));
+    assert!(!modpack_root.join("mods/b.jar").exists());
+    assert_eq!(
+        fs::read(modpack_root.join("mods/b.jar.disabled")).unwrap(),
+        b"mod b"
+    );
+
+    update_with(json!(["default", "extra"]));
+    assert!(!modpack_root.join("mods/b.jar.disabled").exists());
+    assert_eq!(fs::read(modpack_root.join("mods/b.jar")).unwrap(), b"mod b");
+}
+
+#[test]
+fn items_sharing_a_download_get_their_own_files() {
+    let root = sandbox();
+    let server = serve(HashMap::from([(String::from("/b.jar"), b"mod b".to_vec())]));
+    let uuid = "shared-download-test";
+    let launcher = Launcher::Vanilla(root.clone());
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    let items = || {
+        vec![
+            ddl_mod("A", "1", format!("{server}/b.jar"), "default"),
+            ddl_mod("B", "1", format!("{server}/b.jar"), "extra"),
+        ]
+    };
+    // Which item keeps the original filename depends on which download finishes first
+    let jars = || {
+        fs::read_dir(modpack_root.join("mods"))
+            .unwrap()
+            .map(|x| x.unwrap().path())
+            .filter(|x| x.extension().is_some_and(|ext| ext == "jar"))
+            .map(|x| fs::read(x).unwrap())
+            .collect::<Vec<_>>()
+    };
+    let profile = init_from_manifest(
+        manifest(uuid, "1.0.0", items()),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        launcher.clone(),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    set_enabled_features(&modpack_root, json!(["default", "extra"]));
+    let profile = init_from_manifest(
+        manifest(uuid, "1.0.0", items()),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        launcher.clone(),
+    )
+    .unwrap();
+    futures::executor::block_on(update(&profile, Side::Client, || {})).unwrap();
+    assert_eq!(jars(), vec![b"mod b".to_vec(), b"mod b".to_vec()]);
+
+    set_enabled_features(&modpack_root, json!(["default"]));
+    let profile = init_from_manifest(
+        manifest(uuid, "1.0.0", items()),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        launcher,
+    )
+    .unwrap();
+    futures::executor::block_on(update(&profile, Side::Client, || {})).unwrap();
+    assert_eq!(jars(), vec![b"mod b".to_vec()]);
+}
+
+#[test]
+fn ddl_filenames_are_decoded_and_collisions_disambiguated() {
+    let root = sandbox();
+    let server = serve(HashMap::from([
+        (String::from("/My%20Mod.jar"), b"encoded".to_vec()),
+        (String::from("/first/mod.jar"), b"first".to_vec()),
+        (String::from("/second/mod.jar"), b"second".to_vec()),
+    ]));
+    let uuid = "filename-test";
+    let manifest = manifest(
+        uuid,
+        "1.0.0",
+        vec![
+            ddl_mod("Encoded", "1", format!("{server}/My%20Mod.jar"), "default"),
+            ddl_mod("First", "1", format!("{server}/first/mod.jar"), "default"),
+            ddl_mod("Second", "1", format!("{server}/second/mod.jar"), "default"),
+        ],
+    );
+    let profile = init_from_manifest(
+        manifest,
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let mods_dir = root.join(".WC_OVHL").join(uuid).join("mods");
+    assert_eq!(fs::read(mods_dir.join("My Mod.jar")).unwrap(), b"encoded");
+    // Both are downloaded concurrently so either one may get the original name
+    let mut contents = [
+        fs::read(mods_dir.join("mod.jar")).unwrap(),
+        fs::read(mods_dir.join("mod-default.jar")).unwrap(),
+    ];
+    contents.sort();
+    assert_eq!(contents, [b"first".to_vec(), b"second".to_vec()]);
+}
+
+#[test]
+fn new_items_dont_overwrite_installed_files() {
+    let root = sandbox();
+    let server = serve(HashMap::from([
+        (String::from("/first/mod.jar"), b"first".to_vec()),
+        (String::from("/second/mod.jar"), b"second".to_vec()),
+    ]));
+    let uuid = "installed-collision-test";
+    let launcher = Launcher::Vanilla(root.clone());
+    let first = ddl_mod("First", "1", format!("{server}/first/mod.jar"), "default");
+    let profile = init_from_manifest(
+        manifest(uuid, "1.0.0", vec![first.clone()]),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        launcher.clone(),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+    let user_file = root.join(".WC_OVHL").join(uuid).join("mods/user.jar");
+    fs::write(&user_file, b"user").unwrap();
+
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.1",
+            vec![
+                first,
+                ddl_mod("Second", "1", format!("{server}/second/mod.jar"), "default"),
+            ],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        launcher,
+    )
+    .unwrap();
+    futures::executor::block_on(update(&profile, Side::Client, || {})).unwrap();
+
+    let mods_dir = root.join(".WC_OVHL").join(uuid).join("mods");
+    assert_eq!(fs::read(mods_dir.join("mod.jar")).unwrap(), b"first");
+    assert_eq!(fs::read(mods_dir.join("mod-default.jar")).unwrap(), b"second");
+    assert_eq!(fs::read(user_file).unwrap(), b"user");
+}
+
+#[test]
+fn export_zips_installed_pack() {
+    let root = sandbox();
+    let server = serve(HashMap::from([(String::from("/export.jar"), b"export".to_vec())]));
+    let uuid = "export-test";
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.0",
+            vec![ddl_mod("Export", "1", format!("{server}/export.jar"), "default")],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let dest = root.join("export-test.zip");
+    export_pack(&profile, &dest).unwrap();
+    let mut archive = zip::ZipArchive::new(fs::File::open(&dest).unwrap()).unwrap();
+    let mut contents = String::new();
+    archive
+        .by_name("mods/export.jar")
+        .unwrap()
+        .read_to_string(&mut contents)
+        .unwrap();
+    assert_eq!(contents, "export");
+    assert!(archive.by_name("manifest.json").is_ok());
+}
+
+#[test]
+fn manifest_is_fetched_from_configured_endpoint() {
+    let root = sandbox();
+    let uuid = "endpoint-test";
+    let mod_server = serve(HashMap::from([(String::from("/a.jar"), b"mod a".to_vec())]));
+    let pack = manifest(
+        uuid,
+        "1.0.0",
+        vec![ddl_mod("A", "1", format!("{mod_server}/a.jar"), "default")],
+    );
+    let raw_server = serve(HashMap::from([(
+        String::from("/Owner/repo/main/manifest.json"),
+        serde_json::to_vec(&pack).unwrap(),
+    )]));
+    root.config(json!({ "github_raw": format!("{raw_server}/") }));
+
+    let profile = futures::executor::block_on(init(
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    ))
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    assert_eq!(fs::read(modpack_root.join("mods/a.jar")).unwrap(), b"mod a");
+}
+
+#[test]
+fn single_enabled_shaderpack_is_activated() {
+    let root = sandbox();
+    let server = serve(HashMap::from([(String::from("/shader.zip"), b"shader".to_vec())]));
+    let uuid = "shaderpack-test";
+    let mut pack = serde_json::to_value(manifest(uuid, "1.0.0", vec![])).unwrap();
+    pack["shaderpacks"] = json!([ddl_mod("Shader", "1", format!("{server}/shader.zip"), "default")]);
+    pack["activate_shaderpack"] = json!(true);
+    let profile = init_from_manifest(
+        serde_json::from_value(pack).unwrap(),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let properties = root
+        .join(".WC_OVHL")
+        .join(uuid)
+        .join("config/iris.properties");
+    let properties = fs::read_to_string(properties).unwrap();
+    assert!(properties.lines().any(|x| x == "shaderPack=shader.zip"));
+    assert!(properties.lines().any(|x| x == "enableShaders=true"));
+}
+
+#[test]
+fn shaderpack_is_activated_when_features_change() {
+    let root = sandbox();
+    let server = serve(HashMap::from([(String::from("/a.zip"), b"shader a".to_vec())]));
+    let uuid = "shaderpack-features-test";
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    let pack = || {
+        let mut pack = serde_json::to_value(manifest(uuid, "1.0.0", vec![])).unwrap();
+        pack["shaderpacks"] = json!([ddl_mod("A", "1", format!("{server}/a.zip"), "extra")]);
+        pack["activate_shaderpack"] = json!(true);
+        init_from_manifest(
+            serde_json::from_value(pack).unwrap(),
+            String::from("Owner/repo/"),
+            String::from("main"),
+            Launcher::Vanilla(root.clone()),
+        )
+        .unwrap()
+    };
+    futures::executor::block_on(install(&pack(), Side::Client, || {})).unwrap();
+    assert!(!modpack_root.join("config/iris.properties").exists());
+
+    // Same version, so only the features are modified
+    set_enabled_features(&modpack_root, json!(["default", "extra"]));
+    futures::executor::block_on(update(&pack(), Side::Client, || {})).unwrap();
+
+    let properties = fs::read_to_string(modpack_root.join("config/iris.properties")).unwrap();
+    assert!(properties.lines().any(|x| x == "shaderPack=a.zip"), "{properties}");
+}
+
+#[test]
+fn overrides_take_precedence_over_features() {
+    let root = sandbox();
+    let server = serve(HashMap::from([
+        (String::from("/a.jar"), b"mod a".to_vec()),
+        (String::from("/b.jar"), b"mod b".to_vec()),
+    ]));
+    let uuid = "overrides-test";
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    fs::create_dir_all(&modpack_root).unwrap();
+    fs::write(
+        modpack_root.join("overrides.json"),
+        r#"{"disable": ["A"], "enable": ["B"]}"#,
+    )
+    .unwrap();
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.0",
+            vec![
+                ddl_mod("A", "1", format!("{server}/a.jar"), "default"),
+                ddl_mod("B", "1", format!("{server}/b.jar"), "extra"),
+            ],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    assert!(!modpack_root.join("mods/a.jar").exists());
+    assert_eq!(fs::read(modpack_root.join("mods/b.jar")).unwrap(), b"mod b");
+}
+
+#[test]
+fn default_server_is_added_to_servers_dat() {
+    let root = sandbox();
+    let uuid = "server-test";
+    let mut pack = serde_json::to_value(manifest(uuid, "1.0.0", vec![])).unwrap();
+    pack["default_server"] = json!({ "name": "Wynncraft", "address": "play.wynncraft.com" });
+    let profile = init_from_manifest(
+        serde_json::from_value(pack).unwrap(),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let servers_dat = fs::read(root.join(".WC_OVHL").join(uuid).join("servers.dat")).unwrap();
+    let servers: JsonValue = fastnbt::from_bytes(&servers_dat).unwrap();
+    assert_eq!(
+        servers["servers"],
+        json!([{ "name": "Wynncraft", "ip": "play.wynncraft.com" }])
+    );
+}
+
+#[test]
+fn pack_status_reads_local_manifest() {
+    let root = sandbox();
+    let launcher = Launcher::Vanilla(root.clone());
+    let status =
+        futures::executor::block_on(pack_status(&launcher, "Owner/status/", "main", false)).unwrap();
+    assert!(!status.installed);
+
+    let profile = init_from_manifest(
+        manifest("status-test", "1.2.0", vec![]),
+        String::from("Owner/status/"),
+        String::from("main"),
+        launcher.clone(),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let status =
+        futures::executor::block_on(pack_status(&launcher, "Owner/status/", "main", false)).unwrap();
+    assert!(status.installed);
+    assert_eq!(status.modpack_version.as_deref(), Some("1.2.0"));
+    assert_eq!(status.enabled_features, vec![String::from("default")]);
+    assert_eq!(status.update_available, None);
+}
+
+#[test]
+fn priority_prefixes_the_filename() {
+    let root = sandbox();
+    let server = serve(HashMap::from([(String::from("/early.jar"), b"early".to_vec())]));
+    let uuid = "priority-test";
+    let mut early = ddl_mod("Early", "1", format!("{server}/early.jar"), "default");
+    early["priority"] = json!(5);
+    let profile = init_from_manifest(
+        manifest(uuid, "1.0.0", vec![early]),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    assert_eq!(fs::read(modpack_root.join("mods/05_early.jar")).unwrap(), b"early");
+}
+
+#[test]
+fn unmanaged_files_are_found_and_removed() {
+    let root = sandbox();
+    let server = serve(HashMap::from([(String::from("/a.jar"), b"mod a".to_vec())]));
+    let uuid = "unmanaged-test";
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.0",
+            vec![ddl_mod("A", "1", format!("{server}/a.jar"), "default")],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    let stray = modpack_root.join("mods/nested/stray.jar");
+    fs::create_dir_all(stray.parent().unwrap()).unwrap();
+    fs::write(&stray, "stray").unwrap();
+    fs::write(modpack_root.join("options.txt"), "kept").unwrap();
+
+    let unmanaged = find_unmanaged_files(&profile).unwrap();
+    assert_eq!(unmanaged, vec![stray.clone()]);
+    assert!(remove_unmanaged_files(&profile, &[modpack_root.join("options.txt")]).is_err());
+
+    remove_unmanaged_files(&profile, &unmanaged).unwrap();
+    assert!(!stray.exists());
+    assert!(modpack_root.join("mods/a.jar").exists());
+    assert!(modpack_root.join("options.txt").exists());
+}
+
+#[test]
+fn migrate_moves_pack_to_multimc() {
+    let root = sandbox();
+    let server = serve(HashMap::from([(String::from("/a.jar"), b"mod a".to_vec())]));
+    let uuid = "migrate-test";
+    let vanilla = Launcher::Vanilla(root.clone());
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.0",
+            vec![ddl_mod("A", "1", format!("{server}/a.jar"), "default")],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        vanilla.clone(),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let multimc_root = root.join("PrismLauncher");
+    fs::create_dir_all(multimc_root.join("instances")).unwrap();
+    let multimc = Launcher::MultiMC(multimc_root.clone());
+    futures::executor::block_on(migrate(&vanilla, &multimc, uuid)).unwrap();
+
+    let old_root = root.join(".WC_OVHL").join(uuid);
+    let instance = multimc_root.join("instances").join(uuid);
+    let new_root = instance.join(".minecraft");
+    assert!(!old_root.exists());
+    assert_eq!(fs::read(new_root.join("mods/a.jar")).unwrap(), b"mod a");
+    assert!(instance.join("instance.cfg").exists());
+    assert!(instance.join("mmc-pack.json").exists());
+
+    let local_manifest = read_json(&new_root.join("manifest.json"));
+    assert_eq!(
+        PathBuf::from(local_manifest["mods"][0]["path"].as_str().unwrap()),
+        new_root.join("mods/a.jar")
+    );
+    let profiles = read_json(&root.join(".minecraft/launcher_profiles.json"));
+    assert!(profiles["profiles"][uuid].is_null());
+}
+
+#[test]
+fn install_creates_modrinth_profile() {
+    let root = sandbox();
+    let server = serve(HashMap::from([(String::from("/a.jar"), b"mod a".to_vec())]));
+    let uuid = "modrinth-test";
+    let modrinth_root = root.join("ModrinthApp");
+    fs::create_dir_all(modrinth_root.join("profiles")).unwrap();
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.0",
+            vec![ddl_mod("A", "1", format!("{server}/a.jar"), "default")],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Modrinth(modrinth_root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let profile_dir = modrinth_root.join("profiles").join(uuid);
+    assert_eq!(fs::read(profile_dir.join("mods/a.jar")).unwrap(), b"mod a");
+    let modrinth_profile = read_json(&profile_dir.join("profile.json"));
+    assert_eq!(modrinth_profile["path"], uuid);
+    assert_eq!(modrinth_profile["metadata"]["name"], "Test pack");
+    assert_eq!(modrinth_profile["metadata"]["loader"], "fabric");
+    assert_eq!(modrinth_profile["metadata"]["game_version"], MINECRAFT_VERSION);
+    assert_eq!(modrinth_profile["metadata"]["loader_version"]["id"], LOADER_VERSION);
+}
+
+#[test]
+fn update_recovers_from_unparsable_local_manifest() {
+    let root = sandbox();
+    let server = serve(HashMap::from([(String::from("/a.jar"), b"mod a".to_vec())]));
+    let uuid = "broken-manifest-test";
+    let launcher = Launcher::Vanilla(root.clone());
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.0",
+            vec![ddl_mod("A", "1", format!("{server}/a.jar"), "default")],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        launcher.clone(),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    // Schema an older installer could have written
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    fs::write(modpack_root.join("manifest.json"), r#"{"mods": "not a list"}"#).unwrap();
+    fs::write(modpack_root.join("options.txt"), "kept").unwrap();
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "2.0.0",
+            vec![ddl_mod("A", "1", format!("{server}/a.jar"), "default")],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        launcher,
+    )
+    .unwrap();
+    futures::executor::block_on(update(&profile, Side::Client, || {})).unwrap();
+
+    assert_eq!(fs::read(modpack_root.join("mods/a.jar")).unwrap(), b"mod a");
+    assert_eq!(fs::read_to_string(modpack_root.join("options.txt")).unwrap(), "kept");
+    let local_manifest = read_json(&modpack_root.join("manifest.json"));
+    assert_eq!(local_manifest["modpack_version"], "2.0.0");
+}
+
+#[test]
+fn install_downloads_include_from_url() {
+    use sha2::{Digest, Sha256};
+
+    let root = sandbox();
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(vec![]));
+    zip.start_file("config/included.txt", zip::write::SimpleFileOptions::default())
+        .unwrap();
+    zip.write_all(b"included").unwrap();
+    let zip = zip.finish().unwrap().into_inner();
+    let hash = format!("{:x}", Sha256::digest(&zip));
+    let server = serve(HashMap::from([(String::from("/include.zip"), zip)]));
+    let uuid = "url-include-test";
+    let mut manifest = serde_json::to_value(manifest(uuid, "1.0.0", vec![])).unwrap();
+    // No GitHub release exists for 'Owner/repo', so this only works if the release lookup is skipped
+    manifest["include"] = json!([{
+        "location": "config",
+        "url": format!("{server}/include.zip"),
+        "hash": hash,
+    }]);
+    let profile = init_from_manifest(
+        serde_json::from_value(manifest).unwrap(),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    assert_eq!(
+        fs::read_to_string(modpack_root.join("config/included.txt")).unwrap(),
+        "included"
+    );
+    let local_manifest = read_json(&modpack_root.join("manifest.json"));
+    assert_eq!(local_manifest["included_files"]["default.zip"]["md5"], hash);
+}
+
+#[test]
+fn server_install_skips_client_only_items() {
+    let root = sandbox();
+    let server = serve(HashMap::from([
+        (String::from("/shared.jar"), b"shared".to_vec()),
+        (String::from("/client.jar"), b"client".to_vec()),
+    ]));
+    let uuid = "server-side-test";
+    let mut client_mod = ddl_mod("Client", "1", format!("{server}/client.jar"), "default");
+    client_mod["side"] = json!("client");
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.0",
+            vec![ddl_mod("Shared", "1", format!("{server}/shared.jar"), "default"), client_mod],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Server, || {})).unwrap();
+
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    assert!(modpack_root.join("mods/shared.jar").exists());
+    assert!(!modpack_root.join("mods/client.jar").exists());
+}
+
+#[test]
+fn init_installs_modrinth_ddl_and_release_include() {
+    let root = sandbox();
+    let uuid = "init-e2e-test";
+    let cdn = serve(HashMap::from([
+        (String::from("/modrinth-mod.jar"), b"modrinth mod".to_vec()),
+        (String::from("/ddl.jar"), b"ddl mod".to_vec()),
+    ]));
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(vec![]));
+    zip.start_file("config/released.txt", zip::write::SimpleFileOptions::default())
+        .unwrap();
+    zip.write_all(b"released").unwrap();
+    let zip = zip.finish().unwrap().into_inner();
+    let mut pack = serde_json::to_value(manifest(
+        uuid,
+        "1.0.0",
+        vec![
+            json!({
+                "name": "Modrinth mod",
+                "source": "modrinth",
+                "location": "modrinth-mod",
+                "version": "2.0.0",
+                "id": "default",
+                "authors": [],
+            }),
+            ddl_mod("Ddl mod", "1", format!("{cdn}/ddl.jar"), "default"),
+        ],
+    ))
+    .unwrap();
+    pack["include"] = json!([{ "location": "config" }]);
+    let versions = json!([
+        {
+            "version_number": "1.0.0",
+            "files": [{ "url": format!("{cdn}/old.jar"), "filename": "old.jar" }],
+            "loaders": ["fabric"],
+        },
+        {
+            "version_number": "2.0.0",
+            "files": [{ "url": format!("{cdn}/modrinth-mod.jar"), "filename": "modrinth-mod.jar" }],
+            "loaders": ["fabric"],
+        },
+    ]);
+    let server = serve(HashMap::from([
+        (
+            String::from("/raw/Owner/repo/main/manifest.json"),
+            serde_json::to_vec(&pack).unwrap(),
+        ),
+        (
+            String::from("/modrinth/project/modrinth-mod/version"),
+            serde_json::to_vec(&versions).unwrap(),
+        ),
+        (
+            String::from("/api/Owner/repo/releases/tags/main"),
+            serde_json::to_vec(&json!({
+                "tag_name": "main",
+                "body": r#"{"default.zip":"release-hash"}"#,
+                "assets": [{
+                    "name": "default.zip",
+                    "id": 1,
+                    "browser_download_url": "unused",
+                }],
+            }))
+            .unwrap(),
+        ),
+        (String::from("/api/Owner/repo/releases/assets/1"), zip),
+    ]));
+    root.config(json!({
+        "github_raw": format!("{server}/raw/"),
+        "github_api": format!("{server}/api/"),
+        "modrinth_api": format!("{server}/modrinth/"),
+    }));
+
+    let profile = futures::executor::block_on(init(
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    ))
+    .unwrap();
+    let report = futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    assert!(report.skipped.is_empty());
+    let modpack_root = root.join(".WC_OVHL").join(uuid);
+    assert_eq!(
+        fs::read(modpack_root.join("mods/modrinth-mod.jar")).unwrap(),
+        b"modrinth mod"
+    );
+    assert!(!modpack_root.join("mods/old.jar").exists());
+    assert_eq!(fs::read(modpack_root.join("mods/ddl.jar")).unwrap(), b"ddl mod");
+    assert_eq!(
+        fs::read_to_string(modpack_root.join("config/released.txt")).unwrap(),
+        "released"
+    );
+    let local_manifest = read_json(&modpack_root.join("manifest.json"));
+    assert_eq!(local_manifest["included_files"]["default.zip"]["md5"], "release-hash");
+}
+
+#[test]
+fn loader_profile_is_checked_upstream_on_update() {
+    let root = sandbox();
+    let server = serve(HashMap::from([(String::from("/a.jar"), b"mod a".to_vec())]));
+    let uuid = "loader-upstream-test";
+    let loader_name = format!("fabric-loader-{LOADER_VERSION}-{MINECRAFT_VERSION}");
+    let loader_json = root
+        .join(".minecraft/versions")
+        .join(&loader_name)
+        .join(format!("{loader_name}.json"));
+    let update_to = |version: &str| {
+        let profile = init_from_manifest(
+            manifest(
+                uuid,
+                version,
+                vec![ddl_mod("A", "1", format!("{server}/a.jar"), "default")],
+            ),
+            String::from("Owner/repo/"),
+            String::from("main"),
+            Launcher::Vanilla(root.clone()),
+        )
+        .unwrap();
+        futures::executor::block_on(update(&profile, Side::Client, || {}))
+    };
+    let profile = init_from_manifest(
+        manifest(
+            uuid,
+            "1.0.0",
+            vec![ddl_mod("A", "1", format!("{server}/a.jar"), "default")],
+        ),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    futures::executor::block_on(install(&profile, Side::Client, || {})).unwrap();
+
+    // Republished without changing the loader version
+    let meta = serve(HashMap::from([(
+        format!("/versions/loader/{MINECRAFT_VERSION}/{LOADER_VERSION}/profile/json"),
+        br#"{"id":"republished"}"#.to_vec(),
+    )]));
+    root.config(json!({ "fabric_meta": format!("{meta}/") }));
+    update_to("2.0.0").unwrap();
+    assert_eq!(fs::read_to_string(&loader_json).unwrap(), r#"{"id":"republished"}"#);
+
+    // The installed loader still works, so an unreachable profile doesn't fail the update
+    let meta = serve(HashMap::new());
+    root.config(json!({ "fabric_meta": format!("{meta}/") }));
+    update_to("3.0.0").unwrap();
+    assert_eq!(fs::read_to_string(&loader_json).unwrap(), r#"{"id":"republished"}"#);
+}
+
+#[test]
+fn failed_loader_lookup_is_a_network_error() {
+    let root = sandbox();
+    let mut pack = serde_json::to_value(manifest("latest-loader-test", "1.0.0", vec![])).unwrap();
+    pack["loader"]["version"] = json!("latest");
+    // The sandbox's loader meta only serves the profile, so listing the loader versions fails
+    let profile = init_from_manifest(
+        serde_json::from_value(pack).unwrap(),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(root.clone()),
+    )
+    .unwrap();
+    let res = futures::executor::block_on(install(&profile, Side::Client, || {}));
+    assert!(matches!(res, Err(InstallError::Network(_))), "{res:?}");
+}
@@ -0,0 +1,101 @@
+use std::path::PathBuf;
+
+use installer::{init_from_manifest, lint_manifest, InstallError, Launcher, Manifest};
+use serde_json::json;
+
+fn invalid_manifest() -> serde_json::Value {
+    json!({
+        "manifest_version": 3,
+        "modpack_version": "1.0.0",
+        "name": "Test pack",
+        "subtitle": "Test",
+        "description": "",
+        "icon": false,
+        "uuid": "validation-test",
+        "loader": { "type": "fabric", "version": "1", "minecraft_version": "1.20.4" },
+        "mods": [
+            { "name": "A", "source": "ddl", "location": "", "version": "1", "id": "missing", "authors": [] },
+        ],
+        "shaderpacks": [],
+        "resourcepacks": [],
+        "include": [],
+        "features": [],
+        "min_mem": 4096,
+        "max_mem": 2048,
+    })
+}
+
+#[test]
+fn invalid_manifest_is_still_initialized() {
+    let manifest: Manifest = serde_json::from_value(invalid_manifest()).unwrap();
+    let profile = init_from_manifest(
+        manifest,
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(PathBuf::new()),
+    );
+    assert!(profile.is_ok(), "{:?}", profile.err());
+}
+
+#[test]
+fn unsupported_loader_is_rejected() {
+    let mut manifest = invalid_manifest();
+    manifest["loader"]["type"] = json!("forge");
+    let profile = init_from_manifest(
+        serde_json::from_value(manifest).unwrap(),
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(PathBuf::new()),
+    );
+    match profile {
+        Err(InstallError::InvalidManifest(e)) => assert!(e.contains("Unsupported loader type 'forge'"), "{e}"),
+        other => panic!("Expected an invalid manifest, got {other:?}"),
+    }
+}
+
+#[test]
+fn lint_reports_every_problem() {
+    let mut manifest = invalid_manifest();
+    manifest["loader"]["type"] = json!("forge");
+    let path = std::env::temp_dir().join("installer-lint-validation-test.json");
+    std::fs::write(&path, manifest.to_string()).unwrap();
+    let problems =
+        futures::executor::block_on(lint_manifest(path.to_str().unwrap(), None, None)).unwrap();
+    let problems = problems.join("\n");
+    assert!(problems.contains("Unsupported loader type 'forge'"), "{problems}");
+    assert!(problems.contains("references undeclared feature 'missing'"), "{problems}");
+    assert!(problems.contains("'min_mem' (4096)"), "{problems}");
+}
+
+#[test]
+fn manifest_requiring_newer_installer_is_rejected() {
+    let manifest: Manifest = serde_json::from_value(json!({
+        "manifest_version": 3,
+        "modpack_version": "1.0.0",
+        "name": "Test pack",
+        "subtitle": "Test",
+        "description": "",
+        "icon": false,
+        "uuid": "min-version-test",
+        "loader": { "type": "fabric", "version": "1", "minecraft_version": "1.20.4" },
+        "mods": [],
+        "shaderpacks": [],
+        "resourcepacks": [],
+        "include": [],
+        "features": [],
+        "min_installer_version": "999.0.0",
+    }))
+    .unwrap();
+    let err = init_from_manifest(
+        manifest,
+        String::from("Owner/repo/"),
+        String::from("main"),
+        Launcher::Vanilla(PathBuf::new()),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        InstallError::InstallerOutdated(String::from("Test"), String::from("999.0.0"))
+    );
+    assert!(err.to_string().contains("please update your installer"), "{err}");
+}